@@ -1,7 +1,11 @@
 use crate::wrap::{AsyncCons, AsyncProd};
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use core::{
+    mem::MaybeUninit,
+    num::NonZeroUsize,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+};
 use futures::task::AtomicWaker;
 #[cfg(feature = "alloc")]
 use ringbuf::traits::Split;
@@ -23,6 +27,8 @@ pub struct AsyncRb<S: Storage> {
     base: SharedRb<S>,
     pub(crate) read: AtomicWaker,
     pub(crate) write: AtomicWaker,
+    status: AtomicU32,
+    has_status: AtomicBool,
 }
 
 impl<S: Storage> AsyncRb<S> {
@@ -31,6 +37,21 @@ impl<S: Storage> AsyncRb<S> {
             base,
             read: AtomicWaker::default(),
             write: AtomicWaker::default(),
+            status: AtomicU32::new(0),
+            has_status: AtomicBool::new(false),
+        }
+    }
+
+    /// Status is stored before the producer is closed, so it is visible to the consumer that observed closing.
+    pub(crate) fn set_status(&self, status: u32) {
+        self.status.store(status, Ordering::Relaxed);
+        self.has_status.store(true, Ordering::Relaxed);
+    }
+    pub(crate) fn status(&self) -> Option<u32> {
+        if self.has_status.load(Ordering::Relaxed) {
+            Some(self.status.load(Ordering::Relaxed))
+        } else {
+            None
         }
     }
 }
@@ -53,6 +74,19 @@ impl<S: Storage> Observer for AsyncRb<S> {
     fn write_index(&self) -> usize {
         self.base.write_index()
     }
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        self.base.indices_relaxed()
+    }
+
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        self.base.total_pushed()
+    }
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        self.base.total_popped()
+    }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
         self.base.unsafe_slices(start, end)
@@ -96,6 +130,10 @@ impl<S: Storage> RingBuffer for AsyncRb<S> {
         self.write.wake();
         old
     }
+    #[inline]
+    unsafe fn shift_indices(&self, count: usize) {
+        self.base.shift_indices(count)
+    }
 }
 
 impl<S: Storage> SplitRef for AsyncRb<S> {
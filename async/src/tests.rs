@@ -64,6 +64,34 @@ fn push_pop() {
     );
 }
 
+#[test]
+fn close_with_status() {
+    let (prod, cons) = AsyncHeapRb::<usize>::new(2).split();
+    execute!(
+        async move {
+            let mut prod = prod;
+            for i in 0..COUNT {
+                prod.push(i).await.unwrap();
+            }
+            prod.close_with(42);
+        },
+        async move {
+            let mut cons = cons;
+            for i in 0..COUNT {
+                assert_eq!(cons.pop().await.unwrap(), i);
+            }
+            assert!(cons.pop().await.is_none());
+            assert_eq!(cons.close_status(), Some(42));
+        },
+    );
+
+    let (mut prod, cons) = AsyncHeapRb::<usize>::new(2).split();
+    assert_eq!(cons.close_status(), None);
+    prod.close();
+    assert!(cons.is_closed());
+    assert_eq!(cons.close_status(), None);
+}
+
 #[test]
 fn push_pop_slice() {
     let (prod, cons) = AsyncHeapRb::<usize>::new(3).split();
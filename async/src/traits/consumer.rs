@@ -16,6 +16,11 @@ pub trait AsyncConsumer: Consumer {
     fn is_closed(&self) -> bool {
         !self.write_is_held()
     }
+    /// Status passed by the producer to [`AsyncProducer::close_with`](crate::producer::AsyncProducer::close_with).
+    ///
+    /// Returns `None` if the producer is not closed yet or was closed without status.
+    /// Note that the ring buffer may still contain items pushed before closing.
+    fn close_status(&self) -> Option<u32>;
 
     /// Pop item from the ring buffer waiting asynchronously if the buffer is empty.
     ///
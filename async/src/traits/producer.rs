@@ -13,6 +13,11 @@ pub trait AsyncProducer: Producer {
     fn register_waker(&self, waker: &Waker);
 
     fn close(&mut self);
+    /// Close the producer passing terminal `status` to the consumer.
+    ///
+    /// The status can be retrieved by [`AsyncConsumer::close_status`](crate::consumer::AsyncConsumer::close_status),
+    /// so the end of stream with error can be signaled in-band.
+    fn close_with(&mut self, status: u32);
     /// Whether the corresponding consumer was closed.
     fn is_closed(&self) -> bool {
         !self.read_is_held()
@@ -27,6 +27,12 @@ impl<R: AsyncRbRef> AsyncConsumer for AsyncCons<R> {
     fn close(&mut self) {
         drop(self.base.take());
     }
+    fn close_status(&self) -> Option<u32> {
+        match &self.base {
+            Some(base) if self.is_closed() => base.rb().status(),
+            _ => None,
+        }
+    }
 }
 
 impl<R: AsyncRbRef> Stream for AsyncCons<R> {
@@ -45,6 +45,14 @@ impl<R: AsyncRbRef, const P: bool, const C: bool> Wrap for AsyncWrap<R, P, C> {
     fn into_rb_ref(self) -> R {
         self.base.unwrap().into_rb_ref()
     }
+    unsafe fn into_rb_ref_held(self) -> R {
+        self.base.unwrap().into_rb_ref_held()
+    }
+    unsafe fn from_rb_ref_held(rb: R) -> Self {
+        Self {
+            base: Some(Direct::from_rb_ref_held(rb)),
+        }
+    }
 }
 
 impl<R: AsyncRbRef, const P: bool, const C: bool> Unpin for AsyncWrap<R, P, C> {}
@@ -29,6 +29,12 @@ impl<R: AsyncRbRef> AsyncProducer for AsyncProd<R> {
     fn close(&mut self) {
         drop(self.base.take());
     }
+    fn close_with(&mut self, status: u32) {
+        if let Some(base) = &self.base {
+            base.rb().set_status(status);
+        }
+        self.close();
+    }
 }
 
 impl<R: AsyncRbRef> Sink<<R::Rb as Observer>::Item> for AsyncProd<R> {
@@ -52,6 +52,19 @@ impl<S: Storage, X: Semaphore> Observer for BlockingRb<S, X> {
     fn write_index(&self) -> usize {
         self.base.write_index()
     }
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        self.base.indices_relaxed()
+    }
+
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        self.base.total_pushed()
+    }
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        self.base.total_popped()
+    }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
         self.base.unsafe_slices(start, end)
@@ -92,6 +105,9 @@ impl<S: Storage, X: Semaphore> RingBuffer for BlockingRb<S, X> {
         self.write.give();
         old
     }
+    unsafe fn shift_indices(&self, count: usize) {
+        self.base.shift_indices(count)
+    }
 }
 
 impl<S: Storage, X: Semaphore> SplitRef for BlockingRb<S, X> {
@@ -194,3 +194,12 @@ fn write_read() {
 
     assert_eq!(*smsg, rmsg);
 }
+
+#[test]
+fn wait_error() {
+    use std::{boxed::Box, error::Error, string::ToString};
+
+    let err: Box<dyn Error> = Box::new(WaitError::TimedOut);
+    assert_eq!(err.to_string(), "wait timed out");
+    assert_eq!(WaitError::Closed.to_string(), "opposite end of the ring buffer is closed");
+}
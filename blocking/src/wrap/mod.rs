@@ -2,7 +2,7 @@ mod cons;
 mod prod;
 
 use crate::rb::BlockingRbRef;
-use core::time::Duration;
+use core::{fmt, time::Duration};
 use ringbuf::{
     traits::Based,
     wrap::{caching::Caching, Wrap},
@@ -45,6 +45,16 @@ impl<R: BlockingRbRef, const P: bool, const C: bool> Wrap for BlockingWrap<R, P,
     fn into_rb_ref(self) -> Self::RbRef {
         self.base.into_rb_ref()
     }
+    unsafe fn into_rb_ref_held(self) -> Self::RbRef {
+        self.base.into_rb_ref_held()
+    }
+    unsafe fn from_rb_ref_held(rb: Self::RbRef) -> Self {
+        Self {
+            rb: rb.clone(),
+            base: Caching::from_rb_ref_held(rb),
+            timeout: None,
+        }
+    }
 }
 
 impl<R: BlockingRbRef, const P: bool, const C: bool> AsRef<Self> for BlockingWrap<R, P, C> {
@@ -64,5 +74,16 @@ pub enum WaitError {
     Closed,
 }
 
+impl fmt::Display for WaitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitError::TimedOut => f.write_str("wait timed out"),
+            WaitError::Closed => f.write_str("opposite end of the ring buffer is closed"),
+        }
+    }
+}
+
+impl core::error::Error for WaitError {}
+
 pub use cons::*;
 pub use prod::*;
@@ -1,6 +1,7 @@
 #!/bin/sh
 
 cargo +nightly bench --features=bench && \
+cargo +nightly test --features=read_buf && \
 cd async && \
 cargo +nightly bench --features=bench && \
 echo "Done!"
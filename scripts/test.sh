@@ -2,8 +2,15 @@
 
 cargo test && \
 cargo test --features test_local && \
+cargo test --no-default-features --features std && \
+cargo test --no-default-features --features std,counters && \
+cargo test --features counters && \
 cargo check --no-default-features --features alloc && \
 cargo check --no-default-features && \
+cargo check --no-default-features --features portable-atomic && \
+cargo test --features embedded-dma,embedded-io,heapless,arbitrary,codec,ffi,log,numa && \
+cargo check --no-default-features --features embedded-dma,embedded-io,heapless,arbitrary,codec,ffi && \
+cargo check --no-default-features --features defmt,embedded-io && \
 cd async && \
 cargo test && \
 cargo test --no-default-features --features alloc && \
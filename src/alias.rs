@@ -1,5 +1,7 @@
 #[cfg(feature = "alloc")]
 use super::storage::Heap;
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+use super::storage::HugePage;
 use super::{
     rb::SharedRb,
     storage::Array,
@@ -10,14 +12,22 @@ use alloc::sync::Arc;
 
 /// Stack-allocated ring buffer with static capacity.
 ///
-/// *Capacity (`N`) must be greater than zero.*
-pub type StaticRb<T, const N: usize> = SharedRb<Array<T, N>>;
+/// *Capacity (`N`) must be greater than zero, otherwise construction fails to compile:*
+///
+/// ```compile_fail,E0080
+/// let rb = ringbuf::StaticRb::<i32, 0>::default();
+/// ```
+///
+/// Capacity is known at compile time, so the compiler constant-folds index wrapping and doesn't emit division even for non-power-of-two `N`.
+///
+/// Indices are stored as `I`. For small rings it may be replaced with a narrower type (e.g. `u8`) to save memory.
+pub type StaticRb<T, const N: usize, I = usize> = SharedRb<Array<T, N>, I>;
 
 /// Alias for [`StaticRb`] producer.
-pub type StaticProd<'a, T, const N: usize> = CachingProd<&'a StaticRb<T, N>>;
+pub type StaticProd<'a, T, const N: usize, I = usize> = CachingProd<&'a StaticRb<T, N, I>>;
 
 /// Alias for [`StaticRb`] consumer.
-pub type StaticCons<'a, T, const N: usize> = CachingCons<&'a StaticRb<T, N>>;
+pub type StaticCons<'a, T, const N: usize, I = usize> = CachingCons<&'a StaticRb<T, N, I>>;
 
 /// Heap-allocated ring buffer.
 #[cfg(feature = "alloc")]
@@ -30,3 +40,7 @@ pub type HeapProd<T> = CachingProd<Arc<HeapRb<T>>>;
 #[cfg(feature = "alloc")]
 /// Alias for [`HeapRb`] consumer.
 pub type HeapCons<T> = CachingCons<Arc<HeapRb<T>>>;
+
+/// Ring buffer in memory mapped with huge pages.
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+pub type HugePageRb<T> = SharedRb<HugePage<T>>;
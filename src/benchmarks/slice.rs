@@ -41,3 +41,57 @@ fn slice_x1000(b: &mut Bencher) {
     });
     black_box(data);
 }
+
+fn slice_x1000_of<T: Copy + Default>(b: &mut Bencher) {
+    let buf = HeapRb::<T>::new(RB_SIZE);
+    let (mut prod, mut cons) = buf.split();
+    prod.push_slice(&[T::default(); 12]);
+    let mut data = [T::default(); 1000];
+    b.iter(|| {
+        prod.push_slice(&data);
+        cons.pop_slice(&mut data);
+    });
+    black_box(data);
+}
+
+#[bench]
+fn slice_x1000_u8(b: &mut Bencher) {
+    slice_x1000_of::<u8>(b);
+}
+
+#[bench]
+fn slice_x1000_u32(b: &mut Bencher) {
+    slice_x1000_of::<u32>(b);
+}
+
+#[bench]
+fn slice_x1000_u8x3(b: &mut Bencher) {
+    slice_x1000_of::<[u8; 3]>(b);
+}
+
+#[bench]
+fn slice_x1000_u64x4(b: &mut Bencher) {
+    slice_x1000_of::<[u64; 4]>(b);
+}
+
+fn slice_x4_cap(b: &mut Bencher, capacity: usize) {
+    let buf = HeapRb::<u64>::new(capacity);
+    let (mut prod, mut cons) = buf.split();
+    prod.push_slice(&[1; 12]);
+    let mut data = [1; 4];
+    b.iter(|| {
+        prod.push_slice(&data);
+        cons.pop_slice(&mut data);
+        black_box(data);
+    });
+}
+
+#[bench]
+fn slice_x4_pow2(b: &mut Bencher) {
+    slice_x4_cap(b, RB_SIZE);
+}
+
+#[bench]
+fn slice_x4_non_pow2(b: &mut Bencher) {
+    slice_x4_cap(b, RB_SIZE - 24);
+}
@@ -0,0 +1,135 @@
+//! Values are serialized with [`postcard`] and framed with 4-byte little-endian length prefix.
+//!
+//! Frame becomes visible to the consumer only when it has been written completely.
+//!
+//! ```
+//! use ringbuf::{codec::{TypedCons, TypedProd}, traits::*, HeapRb};
+//!
+//! let (prod, cons) = HeapRb::<u8>::new(64).split();
+//! let (mut prod, mut cons) = (TypedProd::new(prod), TypedCons::new(cons));
+//!
+//! prod.try_send(&(1u32, "one")).unwrap();
+//! assert_eq!(cons.try_recv::<(u32, String)>().unwrap(), Some((1, "one".to_string())));
+//! assert_eq!(cons.try_recv::<(u32, String)>().unwrap(), None);
+//! ```
+
+use crate::traits::*;
+use alloc::vec::Vec;
+use core::{fmt, mem};
+use serde::{de::DeserializeOwned, Serialize};
+
+const HEADER_LEN: usize = mem::size_of::<u32>();
+
+/// Error of sending or receiving a typed value.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The ring buffer has not enough vacant space for the frame.
+    Full,
+    /// The frame is larger than the ring buffer capacity so it can never be sent.
+    TooLarge,
+    /// Serialization or deserialization failed.
+    Postcard(postcard::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Full => f.write_str("ring buffer is full"),
+            Error::TooLarge => f.write_str("frame is larger than ring buffer capacity"),
+            Error::Postcard(e) => write!(f, "postcard error: {}", e),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<postcard::Error> for Error {
+    fn from(value: postcard::Error) -> Self {
+        Self::Postcard(value)
+    }
+}
+
+/// Producer that sends serialized values over underlying byte producer.
+pub struct TypedProd<P: Producer<Item = u8>> {
+    inner: P,
+    buffer: Vec<u8>,
+}
+
+impl<P: Producer<Item = u8>> TypedProd<P> {
+    /// Wraps byte producer.
+    pub fn new(inner: P) -> Self {
+        Self { inner, buffer: Vec::new() }
+    }
+    /// Underlying byte producer.
+    pub fn get_ref(&self) -> &P {
+        &self.inner
+    }
+    /// Returns underlying byte producer.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Serializes `value` and appends a frame containing it to the ring buffer.
+    ///
+    /// Nothing is appended if an error occurred.
+    pub fn try_send<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.buffer.clear();
+        self.buffer.extend_from_slice(&[0; HEADER_LEN]);
+        self.buffer = postcard::to_extend(value, mem::take(&mut self.buffer))?;
+        let len = match u32::try_from(self.buffer.len() - HEADER_LEN) {
+            Ok(len) if self.buffer.len() <= self.inner.capacity().get() => len,
+            _ => return Err(Error::TooLarge),
+        };
+        if self.buffer.len() > self.inner.vacant_len() {
+            return Err(Error::Full);
+        }
+        self.buffer[..HEADER_LEN].copy_from_slice(&len.to_le_bytes());
+        self.inner.push_slice(&self.buffer);
+        Ok(())
+    }
+}
+
+/// Consumer that receives serialized values from underlying byte consumer.
+pub struct TypedCons<C: Consumer<Item = u8>> {
+    inner: C,
+    buffer: Vec<u8>,
+}
+
+impl<C: Consumer<Item = u8>> TypedCons<C> {
+    /// Wraps byte consumer.
+    pub fn new(inner: C) -> Self {
+        Self { inner, buffer: Vec::new() }
+    }
+    /// Underlying byte consumer.
+    pub fn get_ref(&self) -> &C {
+        &self.inner
+    }
+    /// Returns underlying byte consumer.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    /// Removes a frame from the ring buffer and deserializes a value from it.
+    ///
+    /// Returns `Ok(None)` if there is no complete frame in the ring buffer.
+    /// The frame is removed even if deserialization failed.
+    pub fn try_recv<T: DeserializeOwned>(&mut self) -> Result<Option<T>, Error> {
+        // Producer may push concurrently, so the header and the payload are checked against the same snapshot.
+        let occupied = self.inner.occupied_len();
+        if occupied < HEADER_LEN {
+            return Ok(None);
+        }
+        let mut header = [0; HEADER_LEN];
+        for (dst, src) in header.iter_mut().zip(self.inner.iter()) {
+            *dst = *src;
+        }
+        let len = u32::from_le_bytes(header) as usize;
+        if occupied - HEADER_LEN < len {
+            return Ok(None);
+        }
+        self.inner.skip(HEADER_LEN);
+        self.buffer.resize(len, 0);
+        self.inner.pop_slice(&mut self.buffer);
+        Ok(Some(postcard::from_bytes(&self.buffer)?))
+    }
+}
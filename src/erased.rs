@@ -0,0 +1,296 @@
+//! Item type is known only at runtime as its [`Layout`] and drop function,
+//! so all the ring buffer code is instantiated only once regardless of the number of item types used.
+//! This reduces code size when ring buffers of many different item types are needed.
+//!
+//! [`TypedRb`] is a thin typed wrapper over [`ErasedRb`] which methods only cast pointers and forward calls.
+//!
+//! ```
+//! use ringbuf::erased::TypedRb;
+//!
+//! let mut rb = TypedRb::<String>::new(2);
+//! rb.try_push("a".to_string()).unwrap();
+//! rb.try_push("b".to_string()).unwrap();
+//! assert_eq!(rb.try_push("c".to_string()), Err("c".to_string()));
+//! assert_eq!(rb.try_pop().as_deref(), Some("a"));
+//! ```
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+use core::{
+    alloc::Layout,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ptr,
+};
+
+/// Single-threaded ring buffer of items which type is erased.
+pub struct ErasedRb {
+    ptr: *mut u8,
+    capacity: usize,
+    item: Layout,
+    drop: Option<unsafe fn(*mut u8)>,
+    read: usize,
+    len: usize,
+}
+
+impl ErasedRb {
+    /// Creates an empty ring buffer of `capacity` items of layout `item`.
+    ///
+    /// `drop` is called for each item removed from the ring buffer without being moved out (e.g. on [`Self::clear`]).
+    ///
+    /// *Panics if `capacity` is zero or memory size overflows.*
+    ///
+    /// # Safety
+    ///
+    /// `drop` must be safe to call on a pointer to any item pushed to the ring buffer.
+    pub unsafe fn new(capacity: usize, item: Layout, drop: Option<unsafe fn(*mut u8)>) -> Self {
+        assert!(capacity > 0);
+        let item = item.pad_to_align();
+        let layout = Self::storage_layout(item, capacity);
+        let ptr = if layout.size() == 0 {
+            layout.align() as *mut u8
+        } else {
+            let ptr = alloc(layout);
+            if ptr.is_null() {
+                handle_alloc_error(layout);
+            }
+            ptr
+        };
+        Self {
+            ptr,
+            capacity,
+            item,
+            drop,
+            read: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates an empty ring buffer of `capacity` items of type `T`.
+    ///
+    /// *Panics if `capacity` is zero or memory size overflows.*
+    pub fn new_for<T>(capacity: usize) -> Self {
+        unsafe fn drop_item<T>(ptr: *mut u8) {
+            ptr::drop_in_place(ptr as *mut T);
+        }
+        let drop = if mem::needs_drop::<T>() {
+            Some(drop_item::<T> as unsafe fn(*mut u8))
+        } else {
+            None
+        };
+        unsafe { Self::new(capacity, Layout::new::<T>(), drop) }
+    }
+
+    fn storage_layout(item: Layout, capacity: usize) -> Layout {
+        match item
+            .size()
+            .checked_mul(capacity)
+            .map(|size| Layout::from_size_align(size, item.align()))
+        {
+            Some(Ok(layout)) => layout,
+            _ => panic!("Storage size overflow"),
+        }
+    }
+
+    /// Layout of an item padded to its alignment.
+    #[inline]
+    pub fn item_layout(&self) -> Layout {
+        self.item
+    }
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// The number of items stored in the buffer.
+    #[inline]
+    pub fn occupied_len(&self) -> usize {
+        self.len
+    }
+    /// The number of remaining free places in the buffer.
+    #[inline]
+    pub fn vacant_len(&self) -> usize {
+        self.capacity - self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    fn slot(&self, offset: usize) -> *mut u8 {
+        let index = self.read + offset;
+        let index = if index < self.capacity { index } else { index - self.capacity };
+        unsafe { self.ptr.add(index * self.item.size()) }
+    }
+
+    /// Moves an item from `item` to the end of the ring buffer.
+    ///
+    /// Returns `false` if the ring buffer is full, the item is not moved then.
+    ///
+    /// # Safety
+    ///
+    /// `item` must point to a valid item of the erased type. If `true` is returned then the item is moved and must not be used anymore.
+    pub unsafe fn try_push_raw(&mut self, item: *const u8) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        ptr::copy_nonoverlapping(item, self.slot(self.len), self.item.size());
+        self.len += 1;
+        true
+    }
+
+    /// Moves the oldest item out of the ring buffer to `item`.
+    ///
+    /// Returns `false` if the ring buffer is empty.
+    ///
+    /// # Safety
+    ///
+    /// `item` must be valid for writes of an item of the erased type.
+    pub unsafe fn try_pop_raw(&mut self, item: *mut u8) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        ptr::copy_nonoverlapping(self.slot(0), item, self.item.size());
+        self.advance(1);
+        true
+    }
+
+    fn advance(&mut self, count: usize) {
+        self.read += count;
+        if self.read >= self.capacity {
+            self.read -= self.capacity;
+        }
+        self.len -= count;
+    }
+
+    /// Removes at most `count` oldest items and drops them.
+    ///
+    /// Returns the number of removed items.
+    ///
+    /// If dropping of an item panics then the item is already removed and the following ones are kept.
+    pub fn skip(&mut self, count: usize) -> usize {
+        let count = usize::min(count, self.len);
+        match self.drop {
+            Some(drop) => {
+                for _ in 0..count {
+                    let item = self.slot(0);
+                    // Item is removed before dropping, so it isn't dropped twice if `drop` panics.
+                    self.advance(1);
+                    unsafe { drop(item) };
+                }
+            }
+            None => self.advance(count),
+        }
+        count
+    }
+
+    /// Removes all items and drops them.
+    ///
+    /// Returns the number of removed items.
+    pub fn clear(&mut self) -> usize {
+        self.skip(self.len)
+    }
+}
+
+impl Drop for ErasedRb {
+    fn drop(&mut self) {
+        self.clear();
+        let layout = Self::storage_layout(self.item, self.capacity);
+        if layout.size() != 0 {
+            unsafe { dealloc(self.ptr, layout) };
+        }
+    }
+}
+
+/// Typed wrapper over [`ErasedRb`].
+pub struct TypedRb<T> {
+    inner: ErasedRb,
+    _ghost: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for TypedRb<T> {}
+
+impl<T> TypedRb<T> {
+    /// Creates an empty ring buffer.
+    ///
+    /// *Panics if `capacity` is zero.*
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: ErasedRb::new_for::<T>(capacity),
+            _ghost: PhantomData,
+        }
+    }
+
+    /// Underlying erased ring buffer.
+    pub fn as_erased(&self) -> &ErasedRb {
+        &self.inner
+    }
+    /// Returns underlying erased ring buffer. Stored items are kept and will be dropped by it.
+    pub fn into_erased(self) -> ErasedRb {
+        self.inner
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+    #[inline]
+    pub fn occupied_len(&self) -> usize {
+        self.inner.occupied_len()
+    }
+    #[inline]
+    pub fn vacant_len(&self) -> usize {
+        self.inner.vacant_len()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    /// Appends an item to the ring buffer.
+    ///
+    /// If buffer is full returns an `Err` containing the item that hasn't been appended.
+    #[inline]
+    pub fn try_push(&mut self, elem: T) -> Result<(), T> {
+        let elem = mem::ManuallyDrop::new(elem);
+        if unsafe { self.inner.try_push_raw(&*elem as *const T as *const u8) } {
+            Ok(())
+        } else {
+            Err(mem::ManuallyDrop::into_inner(elem))
+        }
+    }
+
+    /// Removes the oldest item from the ring buffer and returns it.
+    ///
+    /// Returns `None` if the ring buffer is empty.
+    #[inline]
+    pub fn try_pop(&mut self) -> Option<T> {
+        let mut elem = MaybeUninit::<T>::uninit();
+        if unsafe { self.inner.try_pop_raw(elem.as_mut_ptr() as *mut u8) } {
+            Some(unsafe { elem.assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes at most `count` oldest items and drops them.
+    ///
+    /// Returns the number of removed items.
+    #[inline]
+    pub fn skip(&mut self, count: usize) -> usize {
+        self.inner.skip(count)
+    }
+    /// Removes all items and drops them.
+    ///
+    /// Returns the number of removed items.
+    #[inline]
+    pub fn clear(&mut self) -> usize {
+        self.inner.clear()
+    }
+}
@@ -0,0 +1,108 @@
+//! Functions operate on byte [`HeapRb`] split into producer and consumer.
+//!
+//! Handles are pointers to boxed [`HeapProd<u8>`] and [`HeapCons<u8>`],
+//! so Rust side may pass its own handle to foreign code using [`Box::into_raw`](alloc::boxed::Box::into_raw) or take a handle back using [`Box::from_raw`](alloc::boxed::Box::from_raw).
+//!
+//! Producer and consumer may be used from different threads, but each of them must not be used concurrently.
+//!
+//! Functions never panic. Null handles are treated as empty ring buffers.
+
+use crate::{traits::*, HeapCons, HeapProd, HeapRb};
+use alloc::boxed::Box;
+use core::slice;
+
+/// Creates a byte ring buffer of `capacity` and writes its producer and consumer handles to `prod` and `cons`.
+///
+/// Returns `false` if `capacity` is zero or too large, or memory allocation failed.
+///
+/// # Safety
+///
+/// `prod` and `cons` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_create(capacity: usize, prod: *mut *mut HeapProd<u8>, cons: *mut *mut HeapCons<u8>) -> bool {
+    let (p, c) = match HeapRb::<u8>::try_new(capacity) {
+        Ok(rb) => rb.split(),
+        Err(_) => return false,
+    };
+    prod.write(Box::into_raw(Box::new(p)));
+    cons.write(Box::into_raw(Box::new(c)));
+    true
+}
+
+/// Appends up to `len` bytes from `data` to the ring buffer.
+///
+/// Returns count of bytes been appended.
+///
+/// # Safety
+///
+/// `prod` must be null or a valid producer handle. `data` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_push(prod: *mut HeapProd<u8>, data: *const u8, len: usize) -> usize {
+    match prod.as_mut() {
+        Some(prod) if len != 0 => prod.push_slice(slice::from_raw_parts(data, len)),
+        _ => 0,
+    }
+}
+
+/// Removes up to `len` bytes from the ring buffer and writes them to `data`.
+///
+/// Returns count of bytes been removed.
+///
+/// # Safety
+///
+/// `cons` must be null or a valid consumer handle. `data` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_pop(cons: *mut HeapCons<u8>, data: *mut u8, len: usize) -> usize {
+    match cons.as_mut() {
+        Some(cons) if len != 0 => cons.pop_slice(slice::from_raw_parts_mut(data, len)),
+        _ => 0,
+    }
+}
+
+/// Returns count of bytes that can be appended to the ring buffer.
+///
+/// # Safety
+///
+/// `prod` must be null or a valid producer handle.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_vacant_len(prod: *const HeapProd<u8>) -> usize {
+    prod.as_ref().map_or(0, |prod| prod.vacant_len())
+}
+
+/// Returns count of bytes that can be removed from the ring buffer.
+///
+/// # Safety
+///
+/// `cons` must be null or a valid consumer handle.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_occupied_len(cons: *const HeapCons<u8>) -> usize {
+    cons.as_ref().map_or(0, |cons| cons.occupied_len())
+}
+
+/// Destroys producer handle.
+///
+/// The ring buffer is freed when both producer and consumer are destroyed.
+///
+/// # Safety
+///
+/// `prod` must be null or a valid producer handle. The handle must not be used after that.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_prod_destroy(prod: *mut HeapProd<u8>) {
+    if !prod.is_null() {
+        drop(Box::from_raw(prod));
+    }
+}
+
+/// Destroys consumer handle.
+///
+/// The ring buffer is freed when both producer and consumer are destroyed.
+///
+/// # Safety
+///
+/// `cons` must be null or a valid consumer handle. The handle must not be used after that.
+#[no_mangle]
+pub unsafe extern "C" fn ringbuf_cons_destroy(cons: *mut HeapCons<u8>) {
+    if !cons.is_null() {
+        drop(Box::from_raw(cons));
+    }
+}
@@ -15,6 +15,8 @@
 //! + [`SharedRb`]. Can be shared between threads. Its frequently used instances:
 //!   + [`HeapRb`]. Contents are stored in dynamic memory. *Recommended for use in most cases.*
 //!   + [`StaticRb`]. Contents can be stored in statically-allocated memory.
+//!   + [`SplitOnce`](`rb::SplitOnce`). Statically-allocated ring buffer that can be split into `'static` producer and consumer once.
+//! + [`FencedRb`]. Uses plain stores and explicit memory barriers instead of atomics. For mailboxes between cores of heterogeneous SoCs.
 //!
 //! You may also provide your own generic parameters.
 //!
@@ -27,6 +29,14 @@
 //!
 //! For single-threaded usage [`LocalRb`] is recommended because it is slightly faster than [`SharedRb`] due to absence of CPU cache synchronization.
 //!
+//! # Panics
+//!
+//! Methods that insert, remove or access items do not panic unless it is explicitly stated in their documentation.
+//! So the ring buffer can be used in the environment where panic is unacceptable.
+//!
+//! Only creation of ring buffers and their producers and consumers may panic (e.g. when capacity is zero or producer already exists).
+//! Fallible alternatives are provided for the latter (e.g. [`CachingProd::try_new`]).
+//!
 //! # Examples
 //!
 #![cfg_attr(
@@ -149,6 +159,7 @@ so to perform it concurrently you need to guard the ring buffer with mutex or so
 #![no_std]
 #![allow(clippy::type_complexity)]
 #![cfg_attr(feature = "bench", feature(test))]
+#![cfg_attr(feature = "read_buf", feature(read_buf, core_io_borrowed_buf))]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -157,6 +168,18 @@ extern crate std;
 
 /// Shortcuts for frequently used types.
 mod alias;
+/// Typed channel over byte ring buffer.
+#[cfg(feature = "codec")]
+pub mod codec;
+/// Type-erased ring buffer.
+#[cfg(feature = "alloc")]
+pub mod erased;
+/// C API for byte ring buffers.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Log sink storing records in a ring buffer.
+#[cfg(feature = "log")]
+pub mod logger;
 /// Ring buffer implementations.
 pub mod rb;
 /// Storage types.
@@ -174,7 +197,7 @@ pub mod wrap;
 mod tests;
 
 pub use alias::*;
-pub use rb::{LocalRb, SharedRb};
+pub use rb::{FencedRb, LocalRb, SharedRb};
 pub use traits::{consumer, producer};
 pub use transfer::transfer;
 pub use wrap::{CachingCons, CachingProd, Cons, Obs, Prod};
@@ -0,0 +1,80 @@
+use crate::traits::*;
+use core::fmt::{self, Write};
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    vec::Vec,
+};
+
+/// In-memory logger that stores the latest log records in a byte ring buffer.
+///
+/// When the ring buffer is full the oldest bytes are overwritten,
+/// so the first record in the ring buffer may be truncated.
+///
+/// Each record is formatted as `[LEVEL target] message` followed by a newline.
+///
+/// ```
+/// use ringbuf::{logger::RbLogger, HeapRb};
+///
+/// let logger = RbLogger::new(HeapRb::<u8>::new(64), log::LevelFilter::Info);
+/// log::Log::log(&logger, &log::Record::builder().level(log::Level::Info).target("app").args(format_args!("started")).build());
+/// assert_eq!(logger.drain(), b"[INFO app] started\n");
+/// ```
+pub struct RbLogger<B: RingBuffer<Item = u8>> {
+    rb: Mutex<B>,
+    max_level: LevelFilter,
+}
+
+impl<B: RingBuffer<Item = u8>> RbLogger<B> {
+    /// Creates a logger that stores records with level up to `max_level` in `rb`.
+    pub fn new(rb: B, max_level: LevelFilter) -> Self {
+        Self {
+            rb: Mutex::new(rb),
+            max_level,
+        }
+    }
+
+    /// Locks the ring buffer to access stored bytes directly.
+    pub fn lock(&self) -> MutexGuard<'_, B> {
+        self.rb.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Removes all stored bytes and returns them.
+    pub fn drain(&self) -> Vec<u8> {
+        let mut rb = self.lock();
+        let mut bytes = Vec::with_capacity(rb.occupied_len());
+        let (left, right) = rb.as_slices();
+        bytes.extend_from_slice(left);
+        bytes.extend_from_slice(right);
+        rb.clear();
+        bytes
+    }
+
+    pub fn into_inner(self) -> B {
+        self.rb.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct Overwrite<'a, B: RingBuffer<Item = u8>>(&'a mut B);
+
+impl<'a, B: RingBuffer<Item = u8>> Write for Overwrite<'a, B> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.push_slice_overwrite(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<B: RingBuffer<Item = u8> + Send> Log for RbLogger<B> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.max_level
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let mut rb = self.lock();
+            let _ = writeln!(Overwrite(&mut *rb), "[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,58 @@
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{fence, AtomicU32, Ordering};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{fence, AtomicU32, Ordering};
+
+/// 64-bit counter that uses only 32-bit atomic loads and stores.
+///
+/// Counter must be stored by a single side only, while it can be loaded by any side.
+/// Its halves are guarded by a sequence number like in seqlock,
+/// but the sequence number is updated only when the high half changes, so usually a store is a single plain store.
+///
+/// The layout is `#[repr(C)]` and is the same on all targets: sequence number, low half and high half.
+#[repr(C)]
+pub(crate) struct SeqCounter {
+    seq: AtomicU32,
+    low: AtomicU32,
+    high: AtomicU32,
+}
+
+impl SeqCounter {
+    pub const fn new(value: u64) -> Self {
+        Self {
+            seq: AtomicU32::new(0),
+            low: AtomicU32::new(value as u32),
+            high: AtomicU32::new((value >> 32) as u32),
+        }
+    }
+
+    /// Loads the counter value. Ordering is always [`Ordering::Acquire`].
+    pub fn load(&self, _: Ordering) -> u64 {
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            let (low, high) = (self.low.load(Ordering::Relaxed), self.high.load(Ordering::Relaxed));
+            fence(Ordering::Acquire);
+            if seq & 1 == 0 && self.seq.load(Ordering::Relaxed) == seq {
+                return ((high as u64) << 32) | low as u64;
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Stores the counter value. Ordering is always [`Ordering::Release`].
+    ///
+    /// Must not be called concurrently.
+    pub fn store(&self, value: u64, _: Ordering) {
+        let (low, high) = (value as u32, (value >> 32) as u32);
+        if high == self.high.load(Ordering::Relaxed) {
+            self.low.store(low, Ordering::Release);
+        } else {
+            let seq = self.seq.load(Ordering::Relaxed);
+            self.seq.store(seq.wrapping_add(1), Ordering::Relaxed);
+            fence(Ordering::Release);
+            self.low.store(low, Ordering::Relaxed);
+            self.high.store(high, Ordering::Relaxed);
+            self.seq.store(seq.wrapping_add(2), Ordering::Release);
+        }
+    }
+}
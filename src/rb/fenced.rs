@@ -0,0 +1,332 @@
+#[cfg(feature = "counters")]
+use super::counter::SeqCounter;
+use super::{
+    macros::{rb_impl_eq, rb_impl_init},
+    traits::Index,
+    utils::ranges,
+};
+#[cfg(feature = "counters")]
+use crate::traits::utils::index_sub;
+use crate::{
+    storage::{Array, Storage},
+    traits::{
+        consumer::{impl_blocking_consumer_traits, impl_consumer_traits, Consumer},
+        observer::impl_observer_traits,
+        producer::{impl_blocking_producer_traits, impl_producer_traits, Producer},
+        utils::index_add,
+        Observer, RingBuffer, Split, SplitRef,
+    },
+    wrap::{CachingCons, CachingProd},
+};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, sync::Arc};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{fence, AtomicBool, Ordering};
+use core::{
+    cell::UnsafeCell,
+    mem::{ManuallyDrop, MaybeUninit},
+    num::NonZeroUsize,
+    ptr::{self, addr_of_mut},
+};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{fence, AtomicBool, Ordering};
+
+/// Value that is accessed only by volatile loads and stores.
+#[repr(transparent)]
+struct Volatile<T: Copy> {
+    value: UnsafeCell<T>,
+}
+
+impl<T: Copy> Volatile<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value: UnsafeCell::new(value),
+        }
+    }
+    #[inline]
+    fn load(&self) -> T {
+        unsafe { self.value.get().read_volatile() }
+    }
+    #[inline]
+    fn store(&self, value: T) {
+        unsafe { self.value.get().write_volatile(value) }
+    }
+}
+
+/// Ring buffer that publishes its indices using plain volatile stores and explicit memory barriers.
+///
+/// It is intended for mailboxes between cores of asymmetric multiprocessing systems
+/// (e.g. Cortex-A and Cortex-M cores communicating over shared SRAM)
+/// where both sides may not share the same atomic implementation.
+///
+/// # Memory ordering
+///
+/// Producer writes items and then executes [`fence`] with [`Ordering::Release`] before storing the write index.
+/// Consumer loads the write index and then executes [`fence`] with [`Ordering::Acquire`] before reading items.
+/// The same is done for the read index in the opposite direction.
+///
+/// Index type `I` must be stored by a single memory access on both sides, so it should not be wider than the machine word.
+///
+/// # Layout
+///
+/// The header layout is `#[repr(C)]` and does not contain any target-specific padding,
+/// so the same ring buffer can be accessed from programs built for different targets
+/// provided that they use the same index type and the storage is placed at the same address.
+///
+/// Total counters (see [`Observer::total_pushed`]) are present only when the `counters` feature is enabled,
+/// so both sides must be built with the same set of features.
+/// Each 64-bit counter is stored as a sequence number followed by low and high 32-bit halves for the same reason,
+/// halves are loaded consistently by rereading the sequence number which changes only when the high half is updated.
+///
+/// # Hold flags
+///
+/// Hold flags are updated by atomic swap like in [`SharedRb`](`super::SharedRb`),
+/// so only one producer and one consumer may exist at once even if they are created concurrently.
+/// Atomic implementations of different cores may be incompatible,
+/// so each end should be held from one core only (e.g. producer on one core and consumer on another).
+#[repr(C)]
+pub struct FencedRb<S: Storage + ?Sized, I: Index = usize> {
+    read_index: Volatile<I>,
+    write_index: Volatile<I>,
+    read_held: AtomicBool,
+    write_held: AtomicBool,
+    #[cfg(feature = "counters")]
+    read_total: SeqCounter,
+    #[cfg(feature = "counters")]
+    write_total: SeqCounter,
+    storage: S,
+}
+
+unsafe impl<S: Storage + Sync + ?Sized, I: Index + Send> Sync for FencedRb<S, I> {}
+
+impl<S: Storage, I: Index> FencedRb<S, I> {
+    /// Constructs ring buffer from storage and indices.
+    ///
+    /// # Safety
+    ///
+    /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
+    /// `read` and `write` positions must be valid (see implementation details).
+    ///
+    /// *Panics if storage is empty or its length exceeds [`Index::MAX_CAPACITY`].*
+    pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
+        assert!(!storage.is_empty());
+        assert!(storage.len() <= I::MAX_CAPACITY);
+        let this = Self {
+            storage,
+            read_index: Volatile::new(I::from_usize(read)),
+            write_index: Volatile::new(I::from_usize(write)),
+            read_held: AtomicBool::new(false),
+            write_held: AtomicBool::new(false),
+            #[cfg(feature = "counters")]
+            read_total: SeqCounter::new(0),
+            #[cfg(feature = "counters")]
+            write_total: SeqCounter::new(0),
+        };
+        #[cfg(feature = "counters")]
+        this.write_total.store(this.occupied_len() as u64, Ordering::Relaxed);
+        this
+    }
+    /// Destructures ring buffer into underlying storage and `read` and `write` indices.
+    ///
+    /// # Safety
+    ///
+    /// Initialized contents of the storage must be properly dropped.
+    pub unsafe fn into_raw_parts(self) -> (S, usize, usize) {
+        let this = ManuallyDrop::new(self);
+        (ptr::read(&this.storage), this.read_index(), this.write_index())
+    }
+}
+
+impl<T, const N: usize, I: Index> FencedRb<Array<T, N>, I> {
+    /// Initializes an empty ring buffer in place.
+    ///
+    /// See [`SharedRb::init_in_place`](`super::SharedRb::init_in_place`) for details.
+    pub fn init_in_place(place: &mut MaybeUninit<Self>) -> &mut Self {
+        let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
+        assert!(N <= I::MAX_CAPACITY);
+        let this = place.as_mut_ptr();
+        unsafe {
+            addr_of_mut!((*this).read_index).write(Volatile::new(I::from_usize(0)));
+            addr_of_mut!((*this).write_index).write(Volatile::new(I::from_usize(0)));
+            addr_of_mut!((*this).read_held).write(AtomicBool::new(false));
+            addr_of_mut!((*this).write_held).write(AtomicBool::new(false));
+            #[cfg(feature = "counters")]
+            addr_of_mut!((*this).read_total).write(SeqCounter::new(0));
+            #[cfg(feature = "counters")]
+            addr_of_mut!((*this).write_total).write(SeqCounter::new(0));
+            place.assume_init_mut()
+        }
+    }
+}
+
+impl<S: Storage + ?Sized, I: Index> Observer for FencedRb<S, I> {
+    type Item = S::Item;
+
+    #[inline]
+    fn capacity(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.storage.len()) }
+    }
+
+    #[inline]
+    fn read_index(&self) -> usize {
+        let index = self.read_index.load();
+        fence(Ordering::Acquire);
+        index.into_usize()
+    }
+    #[inline]
+    fn write_index(&self) -> usize {
+        let index = self.write_index.load();
+        fence(Ordering::Acquire);
+        index.into_usize()
+    }
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        (self.read_index.load().into_usize(), self.write_index.load().into_usize())
+    }
+
+    #[cfg(feature = "counters")]
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        Some(self.write_total.load(Ordering::Relaxed))
+    }
+    #[cfg(feature = "counters")]
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        Some(self.read_total.load(Ordering::Relaxed))
+    }
+
+    unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
+        let (first, second) = ranges(self.capacity(), start, end);
+        (self.storage.slice(first), self.storage.slice(second))
+    }
+    unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<S::Item>], &mut [MaybeUninit<S::Item>]) {
+        let (first, second) = ranges(self.capacity(), start, end);
+        (self.storage.slice_mut(first), self.storage.slice_mut(second))
+    }
+
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        self.read_held.load(Ordering::Acquire)
+    }
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        self.write_held.load(Ordering::Acquire)
+    }
+}
+
+impl<S: Storage + ?Sized, I: Index> Producer for FencedRb<S, I> {
+    #[inline]
+    unsafe fn set_write_index(&self, value: usize) {
+        #[cfg(feature = "counters")]
+        {
+            let count = index_sub(self, value, self.write_index.load().into_usize());
+            let total = self.write_total.load(Ordering::Relaxed);
+            self.write_total.store(total.wrapping_add(count as u64), Ordering::Relaxed);
+        }
+        fence(Ordering::Release);
+        self.write_index.store(I::from_usize(value));
+    }
+}
+
+impl<S: Storage + ?Sized, I: Index> Consumer for FencedRb<S, I> {
+    #[inline]
+    unsafe fn set_read_index(&self, value: usize) {
+        #[cfg(feature = "counters")]
+        {
+            let count = index_sub(self, value, self.read_index.load().into_usize());
+            let total = self.read_total.load(Ordering::Relaxed);
+            self.read_total.store(total.wrapping_add(count as u64), Ordering::Relaxed);
+        }
+        fence(Ordering::Release);
+        self.read_index.store(I::from_usize(value));
+    }
+}
+
+impl<S: Storage + ?Sized, I: Index> RingBuffer for FencedRb<S, I> {
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        self.read_held.swap(flag, Ordering::AcqRel)
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.write_held.swap(flag, Ordering::AcqRel)
+    }
+    unsafe fn shift_indices(&self, count: usize) {
+        fence(Ordering::Release);
+        self.write_index.store(I::from_usize(index_add(self, self.write_index(), count)));
+        self.read_index.store(I::from_usize(index_add(self, self.read_index(), count)));
+    }
+}
+
+impl<S: Storage + ?Sized, I: Index> Drop for FencedRb<S, I> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Storage, I: Index> Split for FencedRb<S, I> {
+    type Prod = CachingProd<Arc<Self>>;
+    type Cons = CachingCons<Arc<Self>>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        Arc::new(self).split()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<S: Storage + ?Sized, I: Index> Split for Arc<FencedRb<S, I>> {
+    type Prod = CachingProd<Self>;
+    type Cons = CachingCons<Self>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        (CachingProd::new(self.clone()), CachingCons::new(self))
+    }
+}
+#[cfg(feature = "alloc")]
+impl<S: Storage + ?Sized, I: Index> Split for Box<FencedRb<S, I>> {
+    type Prod = CachingProd<Arc<FencedRb<S, I>>>;
+    type Cons = CachingCons<Arc<FencedRb<S, I>>>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        Arc::<FencedRb<S, I>>::from(self).split()
+    }
+}
+/// Split the ring buffer by shared reference.
+///
+/// *Panics if producer or consumer already exists.*
+impl<S: Storage + ?Sized, I: Index> Split for &FencedRb<S, I> {
+    type Prod = CachingProd<Self>;
+    type Cons = CachingCons<Self>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        (CachingProd::new(self), CachingCons::new(self))
+    }
+}
+impl<S: Storage + ?Sized, I: Index> SplitRef for FencedRb<S, I> {
+    type RefProd<'a> = CachingProd<&'a Self> where Self: 'a;
+    type RefCons<'a> = CachingCons<&'a Self> where Self: 'a;
+
+    fn split_ref(&mut self) -> (Self::RefProd<'_>, Self::RefCons<'_>) {
+        (CachingProd::new(self), CachingCons::new(self))
+    }
+}
+
+rb_impl_init!(FencedRb, Index);
+rb_impl_eq!(FencedRb, Index);
+
+impl_observer_traits!(FencedRb<S: Storage, I: Index>);
+impl_producer_traits!(FencedRb<S: Storage, I: Index>);
+impl_consumer_traits!(FencedRb<S: Storage, I: Index>);
+impl_blocking_producer_traits!(FencedRb<S: Storage, I: Index>);
+impl_blocking_consumer_traits!(FencedRb<S: Storage, I: Index>);
+
+impl<S: Storage + ?Sized, I: Index> AsRef<Self> for FencedRb<S, I> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+impl<S: Storage + ?Sized, I: Index> AsMut<Self> for FencedRb<S, I> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
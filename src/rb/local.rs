@@ -1,11 +1,19 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::{rb_impl_eq, rb_impl_init},
+    traits::Index,
+    utils::ranges,
+};
+#[cfg(feature = "counters")]
+use crate::traits::utils::index_sub;
 #[cfg(feature = "alloc")]
 use crate::traits::Split;
 use crate::{
-    storage::Storage,
+    storage::{Array, Storage},
     traits::{
-        consumer::{impl_consumer_traits, Consumer},
-        producer::{impl_producer_traits, Producer},
+        consumer::{impl_blocking_consumer_traits, impl_consumer_traits, Consumer},
+        observer::impl_observer_traits,
+        producer::{impl_blocking_producer_traits, impl_producer_traits, Producer},
+        utils::index_add,
         Observer, RingBuffer, SplitRef,
     },
     wrap::{Cons, Prod},
@@ -16,18 +24,23 @@ use core::{
     cell::Cell,
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
-    ptr,
+    ops,
+    ptr::{self, addr_of_mut},
 };
 
-struct End {
-    index: Cell<usize>,
+struct End<I: Index> {
+    index: Cell<I>,
+    #[cfg(feature = "counters")]
+    total: Cell<u64>,
     held: Cell<bool>,
 }
 
-impl End {
+impl<I: Index> End<I> {
     fn new(index: usize) -> Self {
         Self {
-            index: Cell::new(index),
+            index: Cell::new(I::from_usize(index)),
+            #[cfg(feature = "counters")]
+            total: Cell::new(0),
             held: Cell::new(false),
         }
     }
@@ -36,26 +49,34 @@ impl End {
 /// Ring buffer for single-threaded use only.
 ///
 /// Slightly faster than multi-threaded version because it doesn't synchronize cache.
-pub struct LocalRb<S: Storage + ?Sized> {
-    read: End,
-    write: End,
+///
+/// Indices are stored as `I` (see [`Index`]). Narrower index type saves memory but limits the capacity.
+pub struct LocalRb<S: Storage + ?Sized, I: Index = usize> {
+    read: End<I>,
+    write: End<I>,
     storage: S,
 }
 
-impl<S: Storage> LocalRb<S> {
+impl<S: Storage, I: Index> LocalRb<S, I> {
     /// Constructs ring buffer from storage and indices.
     ///
     /// # Safety
     ///
     /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
     /// `read` and `write` positions must be valid (see implementation details).
+    ///
+    /// *Panics if storage is empty or its length exceeds [`Index::MAX_CAPACITY`].*
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         assert!(!storage.is_empty());
-        Self {
+        assert!(storage.len() <= I::MAX_CAPACITY);
+        let this = Self {
             storage,
             read: End::new(read),
             write: End::new(write),
-        }
+        };
+        #[cfg(feature = "counters")]
+        this.write.total.set(this.occupied_len() as u64);
+        this
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
     ///
@@ -68,7 +89,23 @@ impl<S: Storage> LocalRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Observer for LocalRb<S> {
+impl<T, const N: usize, I: Index> LocalRb<Array<T, N>, I> {
+    /// Initializes an empty ring buffer in place.
+    ///
+    /// See [`SharedRb::init_in_place`](`super::SharedRb::init_in_place`) for details.
+    pub fn init_in_place(place: &mut MaybeUninit<Self>) -> &mut Self {
+        let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
+        assert!(N <= I::MAX_CAPACITY);
+        let this = place.as_mut_ptr();
+        unsafe {
+            addr_of_mut!((*this).read).write(End::new(0));
+            addr_of_mut!((*this).write).write(End::new(0));
+            place.assume_init_mut()
+        }
+    }
+}
+
+impl<S: Storage + ?Sized, I: Index> Observer for LocalRb<S, I> {
     type Item = S::Item;
 
     #[inline]
@@ -78,11 +115,22 @@ impl<S: Storage + ?Sized> Observer for LocalRb<S> {
 
     #[inline]
     fn read_index(&self) -> usize {
-        self.read.index.get()
+        self.read.index.get().into_usize()
     }
     #[inline]
     fn write_index(&self) -> usize {
-        self.write.index.get()
+        self.write.index.get().into_usize()
+    }
+
+    #[cfg(feature = "counters")]
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        Some(self.write.total.get())
+    }
+    #[cfg(feature = "counters")]
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        Some(self.read.total.get())
     }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
@@ -104,21 +152,31 @@ impl<S: Storage + ?Sized> Observer for LocalRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Producer for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> Producer for LocalRb<S, I> {
     #[inline]
     unsafe fn set_write_index(&self, value: usize) {
-        self.write.index.set(value);
+        #[cfg(feature = "counters")]
+        {
+            let count = index_sub(self, value, self.write_index());
+            self.write.total.set(self.write.total.get() + count as u64);
+        }
+        self.write.index.set(I::from_usize(value));
     }
 }
 
-impl<S: Storage + ?Sized> Consumer for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> Consumer for LocalRb<S, I> {
     #[inline]
     unsafe fn set_read_index(&self, value: usize) {
-        self.read.index.set(value);
+        #[cfg(feature = "counters")]
+        {
+            let count = index_sub(self, value, self.read_index());
+            self.read.total.set(self.read.total.get() + count as u64);
+        }
+        self.read.index.set(I::from_usize(value));
     }
 }
 
-impl<S: Storage + ?Sized> RingBuffer for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> RingBuffer for LocalRb<S, I> {
     #[inline]
     unsafe fn hold_read(&self, flag: bool) -> bool {
         self.read.held.replace(flag)
@@ -127,16 +185,20 @@ impl<S: Storage + ?Sized> RingBuffer for LocalRb<S> {
     unsafe fn hold_write(&self, flag: bool) -> bool {
         self.write.held.replace(flag)
     }
+    unsafe fn shift_indices(&self, count: usize) {
+        self.write.index.set(I::from_usize(index_add(self, self.write_index(), count)));
+        self.read.index.set(I::from_usize(index_add(self, self.read_index(), count)));
+    }
 }
 
-impl<S: Storage + ?Sized> Drop for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> Drop for LocalRb<S, I> {
     fn drop(&mut self) {
         self.clear();
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<S: Storage> Split for LocalRb<S> {
+impl<S: Storage, I: Index> Split for LocalRb<S, I> {
     type Prod = Prod<Rc<Self>>;
     type Cons = Cons<Rc<Self>>;
 
@@ -145,7 +207,7 @@ impl<S: Storage> Split for LocalRb<S> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Rc<LocalRb<S>> {
+impl<S: Storage + ?Sized, I: Index> Split for Rc<LocalRb<S, I>> {
     type Prod = Prod<Self>;
     type Cons = Cons<Self>;
 
@@ -154,15 +216,15 @@ impl<S: Storage + ?Sized> Split for Rc<LocalRb<S>> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Box<LocalRb<S>> {
-    type Prod = Prod<Rc<LocalRb<S>>>;
-    type Cons = Cons<Rc<LocalRb<S>>>;
+impl<S: Storage + ?Sized, I: Index> Split for Box<LocalRb<S, I>> {
+    type Prod = Prod<Rc<LocalRb<S, I>>>;
+    type Cons = Cons<Rc<LocalRb<S, I>>>;
 
     fn split(self) -> (Self::Prod, Self::Cons) {
-        Rc::<LocalRb<S>>::from(self).split()
+        Rc::<LocalRb<S, I>>::from(self).split()
     }
 }
-impl<S: Storage + ?Sized> SplitRef for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> SplitRef for LocalRb<S, I> {
     type RefProd<'a> = Prod<&'a Self> where Self: 'a;
     type RefCons<'a> = Cons<&'a Self> where Self: 'a;
 
@@ -171,18 +233,38 @@ impl<S: Storage + ?Sized> SplitRef for LocalRb<S> {
     }
 }
 
-rb_impl_init!(LocalRb);
+rb_impl_init!(LocalRb, Index);
+rb_impl_eq!(LocalRb, Index);
 
-impl_producer_traits!(LocalRb<S: Storage>);
-impl_consumer_traits!(LocalRb<S: Storage>);
+impl_observer_traits!(LocalRb<S: Storage, I: Index>);
+impl_producer_traits!(LocalRb<S: Storage, I: Index>);
+impl_consumer_traits!(LocalRb<S: Storage, I: Index>);
+impl_blocking_producer_traits!(LocalRb<S: Storage, I: Index>);
+impl_blocking_consumer_traits!(LocalRb<S: Storage, I: Index>);
 
-impl<S: Storage + ?Sized> AsRef<Self> for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> AsRef<Self> for LocalRb<S, I> {
     fn as_ref(&self) -> &Self {
         self
     }
 }
-impl<S: Storage + ?Sized> AsMut<Self> for LocalRb<S> {
+impl<S: Storage + ?Sized, I: Index> AsMut<Self> for LocalRb<S, I> {
     fn as_mut(&mut self) -> &mut Self {
         self
     }
 }
+
+/// Accesses occupied item by its position, where `0` is the eldest one.
+///
+/// *Panics if `index` is out of occupied items.*
+impl<S: Storage + ?Sized, I: Index> ops::Index<usize> for LocalRb<S, I> {
+    type Output = S::Item;
+
+    fn index(&self, index: usize) -> &S::Item {
+        self.get(index).expect("Out of bounds access")
+    }
+}
+impl<S: Storage + ?Sized, I: Index> ops::IndexMut<usize> for LocalRb<S, I> {
+    fn index_mut(&mut self, index: usize) -> &mut S::Item {
+        self.get_mut(index).expect("Out of bounds access")
+    }
+}
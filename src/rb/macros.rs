@@ -1,33 +1,202 @@
 macro_rules! rb_impl_init {
-    ($type:ident) => {
-        impl<T, const N: usize> Default for $type<crate::storage::Array<T, N>> {
+    ($type:ident, $index:ident) => {
+        impl<T, const N: usize, I: $index> Default for $type<crate::storage::Array<T, N>, I> {
             fn default() -> Self {
+                let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
                 unsafe { Self::from_raw_parts(crate::utils::uninit_array().into(), usize::default(), usize::default()) }
             }
         }
 
         impl<T, const N: usize> From<[T; N]> for $type<crate::storage::Array<T, N>> {
             fn from(value: [T; N]) -> Self {
+                let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
                 let (read, write) = (0, value.len());
                 unsafe { Self::from_raw_parts(crate::utils::array_to_uninit(value).into(), read, write) }
             }
         }
 
+        #[cfg(feature = "heapless")]
+        impl<T, const N: usize> From<heapless::Vec<T, N>> for $type<crate::storage::Array<T, N>> {
+            fn from(value: heapless::Vec<T, N>) -> Self {
+                let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
+                let (read, write) = (0, value.len());
+                unsafe { Self::from_raw_parts(crate::utils::heapless_vec_to_uninit_array(value).into(), read, write) }
+            }
+        }
+
         #[cfg(feature = "alloc")]
         impl<T> $type<crate::storage::Heap<T>> {
             /// Creates a new instance of a ring buffer.
             ///
-            /// *Panics if allocation failed or `capacity` is zero.*
+            /// *Panics if allocation failed or `capacity` is zero or too large.*
             pub fn new(capacity: usize) -> Self {
                 Self::try_new(capacity).unwrap()
             }
-            /// Creates a new instance of a ring buffer returning an error if allocation failed.
+            /// Creates a new instance of a ring buffer returning an error if `capacity` is zero or too large or allocation failed.
+            pub fn try_new(capacity: usize) -> Result<Self, crate::rb::CapacityError> {
+                if capacity == 0 {
+                    return Err(crate::rb::CapacityError::Zero);
+                }
+                if capacity > <usize as crate::rb::Index>::MAX_CAPACITY {
+                    return Err(crate::rb::CapacityError::TooLarge);
+                }
+                let storage = crate::storage::Heap::try_new(capacity)?;
+                Ok(unsafe { Self::from_raw_parts(storage, usize::default(), usize::default()) })
+            }
+
+            /// Removes all items from the ring buffer and returns them in a vector in order from oldest to newest.
+            pub fn into_vec(mut self) -> alloc::vec::Vec<T> {
+                let mut vec = alloc::vec::Vec::with_capacity($crate::traits::Observer::occupied_len(&self));
+                vec.extend($crate::traits::Consumer::pop_iter(&mut self));
+                vec
+            }
+            /// Returns a vector of cloned items in order from oldest to newest.
+            pub fn to_vec(&self) -> alloc::vec::Vec<T>
+            where
+                T: Clone,
+            {
+                $crate::traits::Consumer::iter(self).cloned().collect()
+            }
+
+            /// Increases capacity to `new_capacity` moving occupied items to the beginning of a new storage in the same order.
+            ///
+            /// Does nothing if `new_capacity` is not greater than the current capacity.
+            ///
+            /// *Panics if allocation failed or `new_capacity` is too large.*
+            pub fn grow(&mut self, new_capacity: usize) {
+                self.try_grow(new_capacity).unwrap()
+            }
+            /// Increases capacity to `new_capacity` returning an error if it is too large or allocation failed.
+            ///
+            /// See [`Self::grow`] for details.
+            pub fn try_grow(&mut self, new_capacity: usize) -> Result<(), crate::rb::CapacityError> {
+                let capacity = $crate::traits::Observer::capacity(self).get();
+                if new_capacity <= capacity {
+                    return Ok(());
+                }
+                if new_capacity > <usize as crate::rb::Index>::MAX_CAPACITY {
+                    return Err(crate::rb::CapacityError::TooLarge);
+                }
+                self.relocate(crate::storage::Heap::try_new(new_capacity)?);
+                Ok(())
+            }
+            /// Reserves capacity for at least `additional` more items than currently occupied.
+            ///
+            /// Capacity is at least doubled to avoid frequent reallocations. Does nothing if there is enough vacant space already.
+            ///
+            /// Returns an error if required capacity is too large or allocation failed.
+            pub fn try_reserve(&mut self, additional: usize) -> Result<(), crate::rb::CapacityError> {
+                if $crate::traits::Observer::vacant_len(self) >= additional {
+                    return Ok(());
+                }
+                let required = $crate::traits::Observer::occupied_len(self).saturating_add(additional);
+                let doubled = 2 * $crate::traits::Observer::capacity(self).get();
+                self.try_grow(core::cmp::max(required, core::cmp::min(doubled, usize::MAX / 2)))
+            }
+
+            /// Reduces capacity to the number of occupied items (but at least one) moving them to a new storage.
+            ///
+            /// *Panics if allocation failed.*
+            pub fn shrink_to_fit(&mut self) {
+                self.shrink_to(0)
+            }
+            /// Reduces capacity to `min_capacity` but not less than the number of occupied items (and at least one).
+            ///
+            /// Does nothing if the current capacity is not greater than that.
+            ///
+            /// *Panics if allocation failed.*
+            pub fn shrink_to(&mut self, min_capacity: usize) {
+                let new_capacity = core::cmp::max(core::cmp::max(min_capacity, $crate::traits::Observer::occupied_len(self)), 1);
+                if new_capacity < $crate::traits::Observer::capacity(self).get() {
+                    self.relocate(crate::storage::Heap::new(new_capacity));
+                }
+            }
+
+            /// Moves occupied items to the beginning of `storage` and replaces current storage with it.
+            ///
+            /// `storage` must be large enough to contain all occupied items.
+            fn relocate(&mut self, storage: crate::storage::Heap<T>) {
+                let capacity = $crate::traits::Observer::capacity(self).get();
+                unsafe {
+                    let (left, right) = $crate::traits::Consumer::occupied_slices(self);
+                    debug_assert!(left.len() + right.len() <= crate::storage::Storage::len(&storage));
+                    let dst = crate::storage::Storage::as_mut_ptr(&storage);
+                    core::ptr::copy_nonoverlapping(left.as_ptr(), dst, left.len());
+                    core::ptr::copy_nonoverlapping(right.as_ptr(), dst.add(left.len()), right.len());
+                    // Move read index to zero, so that occupied items are at the beginning of the new storage.
+                    let read = $crate::traits::Observer::read_index(self);
+                    $crate::traits::RingBuffer::shift_indices(self, 2 * capacity - read);
+                }
+                // Old storage is freed without dropping items.
+                self.storage = storage;
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T, const ALIGN: usize> $type<crate::storage::AlignedStorage<T, ALIGN>> {
+            /// Creates a new instance of a ring buffer which memory is aligned to at least `ALIGN` bytes.
+            ///
+            /// *Panics if `capacity` is zero or `ALIGN` is not a power of two.*
+            pub fn new_aligned(capacity: usize) -> Self {
+                unsafe { Self::from_raw_parts(crate::storage::AlignedStorage::new(capacity), usize::default(), usize::default()) }
+            }
+        }
+
+        #[cfg(all(feature = "hugepage", target_os = "linux"))]
+        impl<T> $type<crate::storage::HugePage<T>> {
+            /// Creates a new instance of a ring buffer in memory mapped with huge pages.
+            ///
+            /// *Panics if mapping failed or `capacity` is zero.*
+            pub fn new_hugepage(capacity: usize) -> Self {
+                Self::try_new_hugepage(capacity).unwrap()
+            }
+            /// Creates a new instance of a ring buffer in memory mapped with huge pages returning an error if mapping failed.
             ///
             /// *Panics if `capacity` is zero.*
-            pub fn try_new(capacity: usize) -> Result<Self, alloc::collections::TryReserveError> {
-                let mut vec = alloc::vec::Vec::new();
-                vec.try_reserve_exact(capacity)?;
-                Ok(unsafe { Self::from_raw_parts(vec.into(), usize::default(), usize::default()) })
+            pub fn try_new_hugepage(capacity: usize) -> std::io::Result<Self> {
+                Ok(unsafe { Self::from_raw_parts(crate::storage::HugePage::try_new(capacity)?, usize::default(), usize::default()) })
+            }
+
+            /// Creates a new instance of a ring buffer in memory bound to NUMA `node`.
+            ///
+            /// *Panics if mapping or binding failed or `capacity` is zero.*
+            #[cfg(feature = "numa")]
+            pub fn new_on_node(capacity: usize, node: usize) -> Self {
+                Self::try_new_on_node(capacity, node).unwrap()
+            }
+            /// Creates a new instance of a ring buffer in memory bound to NUMA `node` returning an error if mapping or binding failed.
+            ///
+            /// See [`HugePage::try_new_on_node`](crate::storage::HugePage::try_new_on_node) for details.
+            ///
+            /// *Panics if `capacity` is zero.*
+            #[cfg(feature = "numa")]
+            pub fn try_new_on_node(capacity: usize, node: usize) -> std::io::Result<Self> {
+                let storage = crate::storage::HugePage::try_new_on_node(capacity, node)?;
+                Ok(unsafe { Self::from_raw_parts(storage, usize::default(), usize::default()) })
+            }
+        }
+
+        /// Creates a full ring buffer with capacity equal to the number of items.
+        ///
+        /// *Panics if iterator is empty.*
+        #[cfg(feature = "alloc")]
+        impl<T> core::iter::FromIterator<T> for $type<crate::storage::Heap<T>> {
+            fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+                Self::from(iter.into_iter().collect::<alloc::boxed::Box<[T]>>())
+            }
+        }
+
+        /// Generates ring buffer with random capacity, items and index offset.
+        #[cfg(all(feature = "alloc", feature = "arbitrary"))]
+        impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for $type<crate::storage::Heap<T>> {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                let items: alloc::vec::Vec<T> = u.arbitrary()?;
+                let capacity = items.len() + u.int_in_range(0..=items.len().max(1))?;
+                let capacity = capacity.max(1);
+                let offset = u.int_in_range(0..=(2 * capacity - 1))?;
+                let mut rb = unsafe { Self::from_raw_parts(crate::storage::Heap::new(capacity), offset, offset) };
+                $crate::traits::Producer::push_iter(&mut rb, items.into_iter());
+                Ok(rb)
             }
         }
 
@@ -49,4 +218,65 @@ macro_rules! rb_impl_init {
     };
 }
 
+/// Ring buffers are compared by their occupied items only, regardless of capacity and index offset.
+macro_rules! rb_impl_eq {
+    ($type:ident, $index:ident) => {
+        impl<S: crate::storage::Storage + ?Sized, I: $index, S2: crate::storage::Storage + ?Sized, I2: $index> PartialEq<$type<S2, I2>>
+            for $type<S, I>
+        where
+            S::Item: PartialEq<S2::Item>,
+        {
+            fn eq(&self, other: &$type<S2, I2>) -> bool {
+                $crate::traits::Observer::occupied_len(self) == $crate::traits::Observer::occupied_len(other)
+                    && $crate::traits::Consumer::iter(self).eq($crate::traits::Consumer::iter(other))
+            }
+        }
+
+        impl<S: crate::storage::Storage + ?Sized, I: $index> Eq for $type<S, I> where S::Item: Eq {}
+
+        impl<S: crate::storage::Storage + ?Sized, I: $index, U> PartialEq<[U]> for $type<S, I>
+        where
+            S::Item: PartialEq<U>,
+        {
+            fn eq(&self, other: &[U]) -> bool {
+                let (left, right) = $crate::traits::Consumer::as_slices(self);
+                if left.len() + right.len() != other.len() {
+                    return false;
+                }
+                let (other_left, other_right) = other.split_at(left.len());
+                left == other_left && right == other_right
+            }
+        }
+
+        impl<S: crate::storage::Storage + ?Sized, I: $index, U> PartialEq<&[U]> for $type<S, I>
+        where
+            S::Item: PartialEq<U>,
+        {
+            fn eq(&self, other: &&[U]) -> bool {
+                *self == **other
+            }
+        }
+
+        impl<S: crate::storage::Storage + ?Sized, I: $index, U, const N: usize> PartialEq<[U; N]> for $type<S, I>
+        where
+            S::Item: PartialEq<U>,
+        {
+            fn eq(&self, other: &[U; N]) -> bool {
+                *self == other[..]
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<S: crate::storage::Storage + ?Sized, I: $index, U> PartialEq<alloc::vec::Vec<U>> for $type<S, I>
+        where
+            S::Item: PartialEq<U>,
+        {
+            fn eq(&self, other: &alloc::vec::Vec<U>) -> bool {
+                *self == other[..]
+            }
+        }
+    };
+}
+
+pub(crate) use rb_impl_eq;
 pub(crate) use rb_impl_init;
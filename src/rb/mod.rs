@@ -1,11 +1,19 @@
+#[cfg(feature = "counters")]
+mod counter;
+/// Ring buffer implementation based on explicit memory barriers.
+pub mod fenced;
 /// Single-threaded ring buffer implementation.
 pub mod local;
 mod macros;
 /// Multi-threaded ring buffer implementation.
 pub mod shared;
+/// Statically-allocated ring buffer that can be split only once.
+pub mod split_once;
 mod traits;
 mod utils;
 
+pub use fenced::FencedRb;
 pub use local::LocalRb;
 pub use shared::SharedRb;
+pub use split_once::SplitOnce;
 pub use traits::*;
@@ -1,29 +1,89 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::{rb_impl_eq, rb_impl_init},
+    traits::{AtomicIndex, Index},
+    utils::ranges,
+};
+#[cfg(feature = "counters")]
+use crate::traits::utils::index_sub;
 #[cfg(feature = "alloc")]
-use crate::traits::Split;
+use crate::{storage::Heap, wrap::Wrap};
 use crate::{
-    storage::Storage,
+    storage::{Array, Storage},
     traits::{
-        consumer::{impl_consumer_traits, Consumer},
-        producer::{impl_producer_traits, Producer},
-        Observer, RingBuffer, SplitRef,
+        consumer::{impl_blocking_consumer_traits, impl_consumer_traits, Consumer},
+        observer::impl_observer_traits,
+        producer::{impl_blocking_producer_traits, impl_producer_traits, Producer},
+        utils::index_add,
+        Observer, RingBuffer, Split, SplitRef,
     },
+    utils::Padded,
     wrap::{CachingCons, CachingProd},
 };
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, sync::Arc};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use core::{
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
-    ptr,
-    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    ptr::{self, addr_of_mut},
 };
-use crossbeam_utils::CachePadded;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Counter of items passed through an index.
+#[cfg(all(feature = "counters", target_has_atomic = "64"))]
+type Counter = core::sync::atomic::AtomicU64;
+/// Counter of items passed through an index.
+///
+/// Targets without 64-bit atomics use a pair of 32-bit halves.
+#[cfg(all(feature = "counters", not(target_has_atomic = "64")))]
+type Counter = super::counter::SeqCounter;
+
+/// Index and total count of items passed through it.
+///
+/// Both are modified by the same side so they are placed in the same cache line.
+struct End<I: AtomicIndex> {
+    index: I::Atomic,
+    #[cfg(feature = "counters")]
+    total: Counter,
+}
+
+impl<I: AtomicIndex> End<I> {
+    fn new(index: usize) -> Self {
+        Self {
+            index: I::new_atomic(I::from_usize(index)),
+            #[cfg(feature = "counters")]
+            total: Counter::new(0),
+        }
+    }
+}
 
 /// Ring buffer that can be shared between threads.
 ///
 /// Note that there is no explicit requirement of `T: Send`. Instead ring buffer will work just fine even with `T: !Send`
 /// until you try to send its producer or consumer to another thread.
+///
+/// # Memory ordering
+///
+/// Producer writes an item into vacant slot and only then publishes it by storing the write index with [`Ordering::Release`].
+/// Consumer loads the write index with [`Ordering::Acquire`] before reading items, so every item it sees is fully written.
+/// The same holds for the read index in the opposite direction: a slot becomes vacant only after the item is moved out of it.
+///
+/// Producer and consumer never wait for each other and never perform read-modify-write operations on indices,
+/// so one of them may live in an interrupt handler while the other one is used by a thread
+/// (or by another interrupt handler of different priority).
+/// Only obtaining and releasing a producer or a consumer uses atomic swap of hold flags.
+///
+/// On targets without native atomic read-modify-write operations (e.g. `thumbv6m`, `riscv32imc`, `avr`)
+/// enable `portable-atomic` feature to use atomic types from [`portable_atomic`](https://docs.rs/portable-atomic) crate.
+/// You will also need to choose its fallback implementation (e.g. its `critical-section` feature).
+///
+/// Indices are padded to the length of a cache line by default.
+/// On single-core targets the padding only wastes memory, so it can be removed by disabling `cache_padding` feature.
+///
+/// To get handles without allocation you can split the ring buffer by shared reference (see [`Split`] implementation for `&SharedRb`).
+/// If the ring buffer is `'static` then the handles are `'static` too and can be moved into an interrupt handler.
 #[cfg_attr(
     feature = "std",
     doc = r##"
@@ -44,30 +104,36 @@ thread::spawn(move || {
 ```
 "##
 )]
-pub struct SharedRb<S: Storage + ?Sized> {
-    read_index: CachePadded<AtomicUsize>,
-    write_index: CachePadded<AtomicUsize>,
+pub struct SharedRb<S: Storage + ?Sized, I: AtomicIndex = usize> {
+    read: Padded<End<I>>,
+    write: Padded<End<I>>,
     read_held: AtomicBool,
     write_held: AtomicBool,
     storage: S,
 }
 
-impl<S: Storage> SharedRb<S> {
+impl<S: Storage, I: AtomicIndex> SharedRb<S, I> {
     /// Constructs ring buffer from storage and indices.
     ///
     /// # Safety
     ///
     /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
     /// `read` and `write` positions must be valid (see implementation details).
+    ///
+    /// *Panics if storage is empty or its length exceeds [`Index::MAX_CAPACITY`](`super::Index::MAX_CAPACITY`).*
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         assert!(!storage.is_empty());
-        Self {
+        assert!(storage.len() <= I::MAX_CAPACITY);
+        let this = Self {
             storage,
-            read_index: CachePadded::new(AtomicUsize::new(read)),
-            write_index: CachePadded::new(AtomicUsize::new(write)),
+            read: Padded::new(End::new(read)),
+            write: Padded::new(End::new(write)),
             read_held: AtomicBool::new(false),
             write_held: AtomicBool::new(false),
-        }
+        };
+        #[cfg(feature = "counters")]
+        this.write.total.store(this.occupied_len() as u64, Ordering::Relaxed);
+        this
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
     ///
@@ -80,7 +146,67 @@ impl<S: Storage> SharedRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Observer for SharedRb<S> {
+impl<T, const N: usize, I: AtomicIndex> SharedRb<Array<T, N>, I> {
+    /// Initializes an empty ring buffer in place.
+    ///
+    /// Only indices and hold flags are written while the storage is left untouched,
+    /// so `place` may be located in a memory section which is not zeroed at startup (e.g. `.uninit`).
+    ///
+    /// Ring buffer is never dropped by `place`, so remaining items will be leaked.
+    ///
+    /// *Panics if `N` exceeds [`Index::MAX_CAPACITY`](`super::Index::MAX_CAPACITY`). Zero `N` fails to compile.*
+    ///
+    /// ```
+    /// use core::{mem::MaybeUninit, ptr::addr_of_mut};
+    /// use ringbuf::{traits::*, StaticRb};
+    ///
+    /// // Usually placed into not zeroed section with `#[link_section = ".uninit.RB"]`.
+    /// static mut RB: MaybeUninit<StaticRb<u8, 4096>> = MaybeUninit::uninit();
+    ///
+    /// let rb: &'static mut StaticRb<u8, 4096> = StaticRb::init_in_place(unsafe { &mut *addr_of_mut!(RB) });
+    /// let (mut prod, mut cons) = (&*rb).split();
+    ///
+    /// prod.try_push(123).unwrap();
+    /// assert_eq!(cons.try_pop(), Some(123));
+    /// ```
+    pub fn init_in_place(place: &mut MaybeUninit<Self>) -> &mut Self {
+        let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
+        assert!(N <= I::MAX_CAPACITY);
+        let this = place.as_mut_ptr();
+        unsafe {
+            addr_of_mut!((*this).read).write(Padded::new(End::new(0)));
+            addr_of_mut!((*this).write).write(Padded::new(End::new(0)));
+            addr_of_mut!((*this).read_held).write(AtomicBool::new(false));
+            addr_of_mut!((*this).write_held).write(AtomicBool::new(false));
+            place.assume_init_mut()
+        }
+    }
+}
+
+impl<T, const N: usize> SharedRb<Array<T, N>> {
+    /// Creates an empty ring buffer in const context.
+    pub(crate) const fn new_const() -> Self {
+        let () = crate::utils::NonZeroCapacity::<N>::ASSERT;
+        assert!(N <= <usize as Index>::MAX_CAPACITY);
+        Self {
+            storage: Array::new(crate::utils::uninit_array()),
+            read: Padded::new(End {
+                index: AtomicUsize::new(0),
+                #[cfg(feature = "counters")]
+                total: Counter::new(0),
+            }),
+            write: Padded::new(End {
+                index: AtomicUsize::new(0),
+                #[cfg(feature = "counters")]
+                total: Counter::new(0),
+            }),
+            read_held: AtomicBool::new(false),
+            write_held: AtomicBool::new(false),
+        }
+    }
+}
+
+impl<S: Storage + ?Sized, I: AtomicIndex> Observer for SharedRb<S, I> {
     type Item = S::Item;
 
     #[inline]
@@ -90,11 +216,27 @@ impl<S: Storage + ?Sized> Observer for SharedRb<S> {
 
     #[inline]
     fn read_index(&self) -> usize {
-        self.read_index.load(Ordering::Acquire)
+        I::load(&self.read.index, Ordering::Acquire).into_usize()
     }
     #[inline]
     fn write_index(&self) -> usize {
-        self.write_index.load(Ordering::Acquire)
+        I::load(&self.write.index, Ordering::Acquire).into_usize()
+    }
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        let read = I::load(&self.read.index, Ordering::Relaxed).into_usize();
+        (read, I::load(&self.write.index, Ordering::Relaxed).into_usize())
+    }
+
+    #[cfg(feature = "counters")]
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        Some(self.write.total.load(Ordering::Relaxed))
+    }
+    #[cfg(feature = "counters")]
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        Some(self.read.total.load(Ordering::Relaxed))
     }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<S::Item>], &[MaybeUninit<S::Item>]) {
@@ -116,21 +258,34 @@ impl<S: Storage + ?Sized> Observer for SharedRb<S> {
     }
 }
 
-impl<S: Storage + ?Sized> Producer for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Producer for SharedRb<S, I> {
     #[inline]
     unsafe fn set_write_index(&self, value: usize) {
-        self.write_index.store(value, Ordering::Release);
+        // Counter is stored before the index so the opposite side sees counted items.
+        #[cfg(feature = "counters")]
+        {
+            let count = index_sub(self, value, I::load(&self.write.index, Ordering::Relaxed).into_usize());
+            let total = self.write.total.load(Ordering::Relaxed);
+            self.write.total.store(total.wrapping_add(count as u64), Ordering::Relaxed);
+        }
+        I::store(&self.write.index, I::from_usize(value), Ordering::Release);
     }
 }
 
-impl<S: Storage + ?Sized> Consumer for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Consumer for SharedRb<S, I> {
     #[inline]
     unsafe fn set_read_index(&self, value: usize) {
-        self.read_index.store(value, Ordering::Release);
+        #[cfg(feature = "counters")]
+        {
+            let count = index_sub(self, value, I::load(&self.read.index, Ordering::Relaxed).into_usize());
+            let total = self.read.total.load(Ordering::Relaxed);
+            self.read.total.store(total.wrapping_add(count as u64), Ordering::Relaxed);
+        }
+        I::store(&self.read.index, I::from_usize(value), Ordering::Release);
     }
 }
 
-impl<S: Storage + ?Sized> RingBuffer for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> RingBuffer for SharedRb<S, I> {
     #[inline]
     unsafe fn hold_read(&self, flag: bool) -> bool {
         self.read_held.swap(flag, Ordering::AcqRel)
@@ -139,16 +294,50 @@ impl<S: Storage + ?Sized> RingBuffer for SharedRb<S> {
     unsafe fn hold_write(&self, flag: bool) -> bool {
         self.write_held.swap(flag, Ordering::AcqRel)
     }
+    unsafe fn shift_indices(&self, count: usize) {
+        let write = index_add(self, self.write_index(), count);
+        I::store(&self.write.index, I::from_usize(write), Ordering::Release);
+        let read = index_add(self, self.read_index(), count);
+        I::store(&self.read.index, I::from_usize(read), Ordering::Release);
+    }
 }
 
-impl<S: Storage + ?Sized> Drop for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Drop for SharedRb<S, I> {
     fn drop(&mut self) {
         self.clear();
     }
 }
 
 #[cfg(feature = "alloc")]
-impl<S: Storage> Split for SharedRb<S> {
+impl<S: Storage, I: AtomicIndex> SharedRb<S, I> {
+    /// Joins producer and consumer back into the ring buffer they were split from.
+    ///
+    /// Capacity of the ring buffer can be changed only when it is not split,
+    /// so this can be used to resize heap ring buffer (see e.g. `grow`) at a point where both sides are stopped and then split it again.
+    ///
+    /// Any wrappers of the ring buffer can be joined, e.g. [frozen](`crate::wrap::Frozen`) or [direct](`crate::wrap::Direct`) ones.
+    ///
+    /// Returns `Err` containing them if they belong to different ring buffers
+    /// or there are other references to the ring buffer (e.g. observers).
+    pub fn try_unsplit<P: Wrap<RbRef = Arc<Self>>, C: Wrap<RbRef = Arc<Self>>>(prod: P, cons: C) -> Result<Self, (P, C)> {
+        if !Arc::ptr_eq(prod.rb_ref(), cons.rb_ref()) {
+            return Err((prod, cons));
+        }
+        drop(unsafe { prod.into_rb_ref_held() });
+        match Arc::try_unwrap(unsafe { cons.into_rb_ref_held() }) {
+            Ok(rb) => {
+                unsafe {
+                    rb.hold_read(false);
+                    rb.hold_write(false);
+                }
+                Ok(rb)
+            }
+            Err(rb) => Err(unsafe { (P::from_rb_ref_held(rb.clone()), C::from_rb_ref_held(rb)) }),
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl<S: Storage, I: AtomicIndex> Split for SharedRb<S, I> {
     type Prod = CachingProd<Arc<Self>>;
     type Cons = CachingCons<Arc<Self>>;
 
@@ -157,7 +346,7 @@ impl<S: Storage> Split for SharedRb<S> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Arc<SharedRb<S>> {
+impl<S: Storage + ?Sized, I: AtomicIndex> Split for Arc<SharedRb<S, I>> {
     type Prod = CachingProd<Self>;
     type Cons = CachingCons<Self>;
 
@@ -166,15 +355,28 @@ impl<S: Storage + ?Sized> Split for Arc<SharedRb<S>> {
     }
 }
 #[cfg(feature = "alloc")]
-impl<S: Storage + ?Sized> Split for Box<SharedRb<S>> {
-    type Prod = CachingProd<Arc<SharedRb<S>>>;
-    type Cons = CachingCons<Arc<SharedRb<S>>>;
+impl<S: Storage + ?Sized, I: AtomicIndex> Split for Box<SharedRb<S, I>> {
+    type Prod = CachingProd<Arc<SharedRb<S, I>>>;
+    type Cons = CachingCons<Arc<SharedRb<S, I>>>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        Arc::<SharedRb<S, I>>::from(self).split()
+    }
+}
+/// Split the ring buffer by shared reference.
+///
+/// Does not require allocation. Useful when the ring buffer is `'static` and the handles need to be moved into an interrupt handler.
+///
+/// *Panics if producer or consumer already exists.*
+impl<S: Storage + ?Sized, I: AtomicIndex> Split for &SharedRb<S, I> {
+    type Prod = CachingProd<Self>;
+    type Cons = CachingCons<Self>;
 
     fn split(self) -> (Self::Prod, Self::Cons) {
-        Arc::<SharedRb<S>>::from(self).split()
+        (CachingProd::new(self), CachingCons::new(self))
     }
 }
-impl<S: Storage + ?Sized> SplitRef for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> SplitRef for SharedRb<S, I> {
     type RefProd<'a> = CachingProd<&'a Self> where Self: 'a;
     type RefCons<'a> = CachingCons<&'a Self> where Self: 'a;
 
@@ -183,18 +385,45 @@ impl<S: Storage + ?Sized> SplitRef for SharedRb<S> {
     }
 }
 
-rb_impl_init!(SharedRb);
+rb_impl_init!(SharedRb, AtomicIndex);
+rb_impl_eq!(SharedRb, AtomicIndex);
 
-impl_producer_traits!(SharedRb<S: Storage>);
-impl_consumer_traits!(SharedRb<S: Storage>);
+impl_observer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
+impl_producer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
+impl_consumer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
+impl_blocking_producer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
+impl_blocking_consumer_traits!(SharedRb<S: Storage, I: AtomicIndex>);
 
-impl<S: Storage + ?Sized> AsRef<Self> for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> AsRef<Self> for SharedRb<S, I> {
     fn as_ref(&self) -> &Self {
         self
     }
 }
-impl<S: Storage + ?Sized> AsMut<Self> for SharedRb<S> {
+impl<S: Storage + ?Sized, I: AtomicIndex> AsMut<Self> for SharedRb<S, I> {
     fn as_mut(&mut self) -> &mut Self {
         self
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, I: AtomicIndex> SharedRb<Heap<T>, I> {
+    /// Clones items visible to consumer `cons` into a new ring buffer of the same capacity keeping indices the same.
+    ///
+    /// Consumer is borrowed while cloning, so items cannot be removed concurrently,
+    /// while items pushed by a concurrent producer after occupied items are observed are not included.
+    /// So it may be taken from a consumer of the split ring buffer as well as from the ring buffer itself if it isn't split.
+    ///
+    /// Lifetime counters are copied from `cons`, but only copied items are counted as pushed after the popped ones.
+    pub fn snapshot<C: Consumer<Item = T> + ?Sized>(cons: &C) -> Self {
+        let (left, right) = cons.as_slices();
+        let read = cons.read_index();
+        let mut rb = unsafe { Self::from_raw_parts(Heap::new(cons.capacity().get()), read, read) };
+        rb.push_iter(left.iter().chain(right).cloned());
+        #[cfg(feature = "counters")]
+        if let Some(popped) = cons.total_popped() {
+            rb.read.total.store(popped, Ordering::Relaxed);
+            rb.write.total.store(popped + rb.occupied_len() as u64, Ordering::Relaxed);
+        }
+        rb
+    }
+}
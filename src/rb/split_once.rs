@@ -0,0 +1,75 @@
+use crate::{utils::Padded, CachingCons, CachingProd, StaticCons, StaticProd, StaticRb};
+use core::{cell::UnsafeCell, mem::MaybeUninit};
+
+/// Statically-allocated ring buffer that can be split into `'static` producer and consumer only once.
+///
+/// Producer and consumer are stored inside `Self`, so mutable references to them can be passed
+/// to interrupt handlers or tasks (e.g. RTIC local resources) without `unsafe` code or reference counting.
+///
+/// ```
+/// use ringbuf::{rb::SplitOnce, traits::*};
+///
+/// static RB: SplitOnce<u8, 16> = SplitOnce::new();
+///
+/// let (prod, cons) = RB.take_split();
+/// prod.try_push(123).unwrap();
+/// assert_eq!(cons.try_pop(), Some(123));
+///
+/// assert!(RB.try_take_split().is_none());
+/// ```
+pub struct SplitOnce<T: 'static, const N: usize> {
+    rb: StaticRb<T, N>,
+    // Producer and consumer update their cached indices, so they are placed on different cache lines.
+    prod: Padded<UnsafeCell<MaybeUninit<StaticProd<'static, T, N>>>>,
+    cons: Padded<UnsafeCell<MaybeUninit<StaticCons<'static, T, N>>>>,
+}
+
+unsafe impl<T: 'static, const N: usize> Sync for SplitOnce<T, N>
+where
+    StaticRb<T, N>: Sync,
+    StaticProd<'static, T, N>: Send,
+    StaticCons<'static, T, N>: Send,
+{
+}
+
+impl<T: 'static, const N: usize> SplitOnce<T, N> {
+    /// Creates an empty ring buffer.
+    ///
+    /// *Zero `N` fails to compile.*
+    pub const fn new() -> Self {
+        Self {
+            rb: StaticRb::new_const(),
+            prod: Padded::new(UnsafeCell::new(MaybeUninit::uninit())),
+            cons: Padded::new(UnsafeCell::new(MaybeUninit::uninit())),
+        }
+    }
+
+    /// Splits the ring buffer into producer and consumer.
+    ///
+    /// Returns `None` if the ring buffer is already split.
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_take_split(&'static self) -> Option<(&'static mut StaticProd<'static, T, N>, &'static mut StaticCons<'static, T, N>)> {
+        // Hold flags are never released because producer and consumer are never dropped.
+        let prod = CachingProd::try_new(&self.rb).ok()?;
+        let cons = CachingCons::try_new(&self.rb).ok()?;
+        // Hold flags guarantee that these cells are written only once.
+        unsafe { Some(((*self.prod.get()).write(prod), (*self.cons.get()).write(cons))) }
+    }
+
+    /// Splits the ring buffer into producer and consumer.
+    ///
+    /// *Panics if the ring buffer is already split.*
+    #[allow(clippy::mut_from_ref)]
+    pub fn take_split(&'static self) -> (&'static mut StaticProd<'static, T, N>, &'static mut StaticCons<'static, T, N>) {
+        match self.try_take_split() {
+            Some(pair) => pair,
+            None => panic!("Ring buffer is already split"),
+        }
+    }
+}
+
+impl<T: 'static, const N: usize> Default for SplitOnce<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
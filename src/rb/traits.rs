@@ -1,9 +1,20 @@
 use crate::traits::RingBuffer;
 #[cfg(feature = "alloc")]
-use alloc::{rc::Rc, sync::Arc};
+use alloc::{collections::TryReserveError, rc::Rc, sync::Arc};
+use atomic::Ordering;
+#[cfg(feature = "alloc")]
+use core::fmt;
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic;
+#[cfg(feature = "portable-atomic")]
+use portable_atomic as atomic;
 
 /// Abstract pointer to the owning ring buffer.
 ///
+/// Wrappers (e.g. [`Prod`](crate::wrap::Prod) and [`Cons`](crate::wrap::Cons)) are generic over it,
+/// so besides `&B`, `Rc<B>` and `Arc<B>` it may be implemented for custom shared pointers
+/// (e.g. a pointer to statically-allocated ring buffer with its own reference counter for systems without allocator).
+///
 /// # Safety
 ///
 /// Implementation must be fair (e.g. not replacing pointers between calls and so on).
@@ -14,16 +25,156 @@ pub unsafe trait RbRef: Clone + AsRef<Self::Rb> {
     fn rb(&self) -> &Self::Rb {
         self.as_ref()
     }
+    /// Whether this is the only reference to the ring buffer, so that it can be taken by [`Self::try_into_rb`].
+    ///
+    /// Always `false` for borrowed references.
+    fn is_unique(&self) -> bool {
+        false
+    }
+    /// Take the ring buffer if this is the only reference to it.
+    ///
+    /// Otherwise returns `Err` containing `self`.
+    fn try_into_rb(self) -> Result<Self::Rb, Self>
+    where
+        Self::Rb: Sized,
+    {
+        Err(self)
+    }
 }
 
-unsafe impl<'a, B: RingBuffer + AsRef<B> + ?Sized> RbRef for &'a B {
+unsafe impl<B: RingBuffer + AsRef<B> + ?Sized> RbRef for &B {
     type Rb = B;
 }
 #[cfg(feature = "alloc")]
 unsafe impl<B: RingBuffer + ?Sized> RbRef for Rc<B> {
     type Rb = B;
+    fn is_unique(&self) -> bool {
+        Rc::strong_count(self) == 1 && Rc::weak_count(self) == 0
+    }
+    fn try_into_rb(self) -> Result<B, Self>
+    where
+        B: Sized,
+    {
+        Rc::try_unwrap(self)
+    }
 }
 #[cfg(feature = "alloc")]
 unsafe impl<B: RingBuffer + ?Sized> RbRef for Arc<B> {
     type Rb = B;
+    fn is_unique(&self) -> bool {
+        Arc::strong_count(self) == 1 && Arc::weak_count(self) == 0
+    }
+    fn try_into_rb(self) -> Result<B, Self>
+    where
+        B: Sized,
+    {
+        Arc::try_unwrap(self)
+    }
 }
+
+/// Unsigned integer type used to store ring buffer indices.
+///
+/// Narrower types reduce memory required for ring buffer bookkeeping but limit its capacity (see [`Self::MAX_CAPACITY`]).
+///
+/// # Safety
+///
+/// Conversion to and from `usize` must be lossless for all integers in range `0..(2 * Self::MAX_CAPACITY)`.
+pub unsafe trait Index: Copy {
+    /// Maximum capacity of the ring buffer which indices can be stored in this type.
+    const MAX_CAPACITY: usize;
+
+    /// Convert from `usize`.
+    fn from_usize(value: usize) -> Self;
+    /// Convert into `usize`.
+    fn into_usize(self) -> usize;
+}
+
+/// Error of creating a ring buffer with capacity given at runtime.
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CapacityError {
+    /// Capacity is zero.
+    Zero,
+    /// Capacity exceeds [`Index::MAX_CAPACITY`].
+    TooLarge,
+    /// Memory allocation failed.
+    Alloc(TryReserveError),
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CapacityError::Zero => f.write_str("ring buffer capacity is zero"),
+            CapacityError::TooLarge => f.write_str("ring buffer capacity is too large"),
+            CapacityError::Alloc(e) => write!(f, "ring buffer allocation failed: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for CapacityError {}
+
+#[cfg(feature = "alloc")]
+impl From<TryReserveError> for CapacityError {
+    fn from(value: TryReserveError) -> Self {
+        Self::Alloc(value)
+    }
+}
+
+/// Index type that can be atomically loaded and stored.
+pub trait AtomicIndex: Index {
+    /// Atomic version of the index type.
+    type Atomic: Send + Sync;
+
+    /// Create a new atomic index.
+    fn new_atomic(value: Self) -> Self::Atomic;
+    /// Load value from atomic index.
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+    /// Store value into atomic index.
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+}
+
+macro_rules! impl_index {
+    ($type:ty, $atomic:ident) => {
+        unsafe impl Index for $type {
+            const MAX_CAPACITY: usize = if (<$type>::MAX as usize) < usize::MAX {
+                <$type>::MAX as usize / 2 + 1
+            } else {
+                usize::MAX / 2
+            };
+
+            #[inline]
+            fn from_usize(value: usize) -> Self {
+                value as $type
+            }
+            #[inline]
+            fn into_usize(self) -> usize {
+                self as usize
+            }
+        }
+
+        impl AtomicIndex for $type {
+            type Atomic = atomic::$atomic;
+
+            #[inline]
+            fn new_atomic(value: Self) -> Self::Atomic {
+                atomic::$atomic::new(value)
+            }
+            #[inline]
+            fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                atomic.load(order)
+            }
+            #[inline]
+            fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                atomic.store(value, order)
+            }
+        }
+    };
+}
+
+impl_index!(u8, AtomicU8);
+impl_index!(u16, AtomicU16);
+#[cfg(not(target_pointer_width = "16"))]
+impl_index!(u32, AtomicU32);
+impl_index!(usize, AtomicUsize);
@@ -1,8 +1,12 @@
 #[cfg(feature = "alloc")]
-use alloc::{boxed::Box, vec::Vec};
-use core::{cell::UnsafeCell, marker::PhantomData, mem::MaybeUninit, ops::Range, ptr::NonNull, slice};
+use alloc::{boxed::Box, collections::TryReserveError, vec::Vec};
 #[cfg(feature = "alloc")]
-use core::{mem::forget, ptr};
+use core::{
+    alloc::Layout,
+    mem::{self, forget},
+    ptr,
+};
+use core::{cell::UnsafeCell, marker::PhantomData, mem::MaybeUninit, ops::Range, ptr::NonNull, slice};
 
 /// Abstract storage for the ring buffer.
 ///
@@ -81,6 +85,54 @@ impl<'a, T> From<&'a mut [MaybeUninit<T>]> for Ref<'a, T> {
         }
     }
 }
+impl<'a, T> Ref<'a, T> {
+    /// Creates storage over a raw memory region.
+    ///
+    /// It can be used to place the ring buffer into memory shared with foreign code.
+    /// For example, on `wasm32` with `atomics` target feature the linear memory is backed by `SharedArrayBuffer`,
+    /// so [`SharedRb`](crate::SharedRb) placed in region allocated by JavaScript can be used
+    /// to transfer items between web workers and the main thread.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, properly aligned and valid for reads and writes of `len` items for the lifetime `'a`.
+    /// The memory must not be accessed through other pointers while storage exists.
+    pub unsafe fn from_raw_parts(ptr: *mut MaybeUninit<T>, len: usize) -> Self {
+        Self {
+            _ghost: PhantomData,
+            ptr,
+            len,
+        }
+    }
+}
+/// Use the whole capacity of [`heapless::Vec`] as a storage.
+///
+/// Vector length is ignored, so its items must be uninitialized.
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use ringbuf::{storage::Ref, traits::*, LocalRb};
+///
+/// let mut vec = heapless::Vec::<MaybeUninit<i32>, 4>::new();
+/// let mut rb = unsafe { LocalRb::<Ref<i32>>::from_raw_parts(Ref::from(&mut vec), 0, 0) };
+/// assert_eq!(rb.capacity().get(), 4);
+///
+/// rb.try_push(123).unwrap();
+/// assert_eq!(rb.try_pop(), Some(123));
+/// ```
+///
+/// To take ownership of existing items convert `heapless::Vec<T, N>` into ring buffer with [`Array`] storage instead
+/// (e.g. `StaticRb::from(vec)`).
+#[cfg(feature = "heapless")]
+impl<'a, T, const N: usize> From<&'a mut heapless::Vec<MaybeUninit<T>, N>> for Ref<'a, T> {
+    fn from(value: &'a mut heapless::Vec<MaybeUninit<T>, N>) -> Self {
+        Self {
+            _ghost: PhantomData,
+            ptr: value.as_mut_ptr(),
+            len: N,
+        }
+    }
+}
 impl<'a, T> From<Ref<'a, T>> for &'a mut [MaybeUninit<T>] {
     fn from(value: Ref<'a, T>) -> Self {
         unsafe { slice::from_raw_parts_mut(value.ptr, value.len) }
@@ -91,6 +143,13 @@ pub struct Owning<T: ?Sized> {
     data: UnsafeCell<T>,
 }
 unsafe impl<T: ?Sized> Sync for Owning<T> where T: Sync {}
+impl<T> Owning<T> {
+    pub(crate) const fn new(value: T) -> Self {
+        Self {
+            data: UnsafeCell::new(value),
+        }
+    }
+}
 impl<T> From<T> for Owning<T> {
     fn from(value: T) -> Self {
         Self {
@@ -130,6 +189,9 @@ unsafe impl<T> Storage for Slice<T> {
     }
 }
 
+/// Heap-allocated storage.
+///
+/// Storage of zero-sized items doesn't allocate memory, so ring buffer of them acts as a pair of counters.
 #[cfg(feature = "alloc")]
 pub struct Heap<T> {
     ptr: *mut MaybeUninit<T>,
@@ -159,11 +221,24 @@ impl<T> Heap<T> {
             len: capacity,
         }
     }
+    /// Creates a storage of `capacity` items returning an error if allocation failed.
+    pub fn try_new(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(capacity)?;
+        let mut this = Self::from(vec);
+        if mem::size_of::<T>() == 0 {
+            this.len = capacity;
+        }
+        Ok(this)
+    }
 }
+/// Storage length is the capacity of the vector.
+///
+/// Vectors of zero-sized items have unbounded capacity, so their length is used instead.
 #[cfg(feature = "alloc")]
 impl<T> From<Vec<MaybeUninit<T>>> for Heap<T> {
     fn from(mut value: Vec<MaybeUninit<T>>) -> Self {
-        let len = value.capacity();
+        let len = if mem::size_of::<T>() == 0 { value.len() } else { value.capacity() };
         let ptr = value.as_mut_ptr();
         forget(value);
         Self { ptr, len }
@@ -190,3 +265,175 @@ impl<T> Drop for Heap<T> {
         drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(self.ptr, self.len)) });
     }
 }
+
+/// Heap-allocated storage which memory is aligned to at least `ALIGN` bytes.
+///
+/// It can be used to make vacant and occupied slices suitable for aligned SIMD loads or `O_DIRECT` I/O.
+///
+/// *`ALIGN` must be a power of two.*
+#[cfg(feature = "alloc")]
+pub struct AlignedStorage<T, const ALIGN: usize> {
+    ptr: *mut MaybeUninit<T>,
+    len: usize,
+}
+#[cfg(feature = "alloc")]
+unsafe impl<T, const ALIGN: usize> Send for AlignedStorage<T, ALIGN> where T: Send {}
+#[cfg(feature = "alloc")]
+unsafe impl<T, const ALIGN: usize> Sync for AlignedStorage<T, ALIGN> where T: Sync {}
+#[cfg(feature = "alloc")]
+unsafe impl<T, const ALIGN: usize> Storage for AlignedStorage<T, ALIGN> {
+    type Item = T;
+    #[inline]
+    fn as_mut_ptr(&self) -> *mut MaybeUninit<T> {
+        self.ptr
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T, const ALIGN: usize> AlignedStorage<T, ALIGN> {
+    fn layout(len: usize) -> Layout {
+        match Layout::array::<T>(len).and_then(|layout| layout.align_to(ALIGN)) {
+            Ok(layout) => layout,
+            Err(_) => panic!("Invalid capacity or alignment"),
+        }
+    }
+
+    /// *Panics if `ALIGN` is not a power of two or memory size overflows.*
+    pub fn new(capacity: usize) -> Self {
+        let layout = Self::layout(capacity);
+        let ptr = if layout.size() == 0 {
+            layout.align() as *mut MaybeUninit<T>
+        } else {
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            if ptr.is_null() {
+                alloc::alloc::handle_alloc_error(layout);
+            }
+            ptr as *mut MaybeUninit<T>
+        };
+        Self { ptr, len: capacity }
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T, const ALIGN: usize> Drop for AlignedStorage<T, ALIGN> {
+    fn drop(&mut self) {
+        let layout = Self::layout(self.len);
+        if layout.size() != 0 {
+            unsafe { alloc::alloc::dealloc(self.ptr as *mut u8, layout) };
+        }
+    }
+}
+
+/// Storage mapped with huge pages.
+///
+/// Huge pages reduce TLB misses for multi-megabyte ring buffers.
+/// Memory is mapped with `MAP_HUGETLB` and falls back to regular pages with transparent huge pages requested via `madvise`
+/// if no huge pages are reserved in the system.
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+pub struct HugePage<T> {
+    ptr: *mut MaybeUninit<T>,
+    len: usize,
+    size: usize,
+}
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+unsafe impl<T> Send for HugePage<T> where T: Send {}
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+unsafe impl<T> Sync for HugePage<T> where T: Sync {}
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+unsafe impl<T> Storage for HugePage<T> {
+    type Item = T;
+    #[inline]
+    fn as_mut_ptr(&self) -> *mut MaybeUninit<T> {
+        self.ptr
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+impl<T> HugePage<T> {
+    /// Mapping size is rounded up to a multiple of this value.
+    pub const PAGE_SIZE: usize = 2 << 20;
+
+    /// *Panics if mapping failed.*
+    pub fn new(capacity: usize) -> Self {
+        Self::try_new(capacity).unwrap()
+    }
+    /// Maps memory for `capacity` items returning an error if mapping failed.
+    pub fn try_new(capacity: usize) -> std::io::Result<Self> {
+        use std::io::{Error, ErrorKind};
+
+        let bytes = capacity.checked_mul(core::mem::size_of::<T>()).ok_or(ErrorKind::OutOfMemory)?;
+        if bytes == 0 {
+            return Ok(Self {
+                ptr: NonNull::dangling().as_ptr(),
+                len: capacity,
+                size: 0,
+            });
+        }
+        if core::mem::align_of::<T>() > Self::PAGE_SIZE {
+            return Err(ErrorKind::InvalidInput.into());
+        }
+        let size = bytes.checked_next_multiple_of(Self::PAGE_SIZE).ok_or(ErrorKind::OutOfMemory)?;
+        let prot = libc::PROT_READ | libc::PROT_WRITE;
+        let flags = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS;
+        let mut addr = unsafe { libc::mmap(ptr::null_mut(), size, prot, flags | libc::MAP_HUGETLB, -1, 0) };
+        if addr == libc::MAP_FAILED {
+            addr = unsafe { libc::mmap(ptr::null_mut(), size, prot, flags, -1, 0) };
+            if addr == libc::MAP_FAILED {
+                return Err(Error::last_os_error());
+            }
+            // Transparent huge pages are only a hint, so failure is ignored.
+            unsafe { libc::madvise(addr, size, libc::MADV_HUGEPAGE) };
+        }
+        Ok(Self {
+            ptr: addr as *mut MaybeUninit<T>,
+            len: capacity,
+            size,
+        })
+    }
+
+    /// *Panics if mapping or binding failed.*
+    #[cfg(feature = "numa")]
+    pub fn new_on_node(capacity: usize, node: usize) -> Self {
+        Self::try_new_on_node(capacity, node).unwrap()
+    }
+    /// Maps memory for `capacity` items and binds it to NUMA `node` so that pages are allocated only on this node.
+    ///
+    /// If the kernel has no NUMA support then node `0` is accepted and memory is left unbound.
+    #[cfg(feature = "numa")]
+    pub fn try_new_on_node(capacity: usize, node: usize) -> std::io::Result<Self> {
+        use std::{io::Error, vec};
+
+        const MPOL_BIND: libc::c_int = 2;
+        const BITS: usize = libc::c_ulong::BITS as usize;
+
+        let this = Self::try_new(capacity)?;
+        if this.size == 0 {
+            return Ok(this);
+        }
+        let mut mask = vec![0 as libc::c_ulong; node / BITS + 1];
+        mask[node / BITS] |= 1 << (node % BITS);
+        // Memory is not touched yet, so all pages will be allocated according to the policy.
+        let (addr, maxnode) = (this.ptr, mask.len() * BITS + 1);
+        let res = unsafe { libc::syscall(libc::SYS_mbind, addr, this.size, MPOL_BIND, mask.as_ptr(), maxnode, 0) };
+        if res != 0 {
+            let err = Error::last_os_error();
+            if !(node == 0 && err.raw_os_error() == Some(libc::ENOSYS)) {
+                return Err(err);
+            }
+        }
+        Ok(this)
+    }
+}
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+impl<T> Drop for HugePage<T> {
+    fn drop(&mut self) {
+        if self.size != 0 {
+            unsafe { libc::munmap(self.ptr as *mut libc::c_void, self.size) };
+        }
+    }
+}
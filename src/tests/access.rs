@@ -244,3 +244,78 @@ fn push_pop() {
     }
     assert_eq!(prod.occupied_len(), 0);
 }
+
+#[test]
+fn peek() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.first(), None);
+
+    assert_eq!(prod.push_iter(0..2), 2);
+    assert_eq!(cons.first(), Some(&0));
+    *cons.first_mut().unwrap() = 10;
+    assert_eq!(cons.occupied_len(), 2);
+    assert_eq!(cons.try_pop(), Some(10));
+    assert_eq!(cons.first(), Some(&1));
+}
+
+#[test]
+fn get() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter(0..2), 2);
+    assert_eq!(cons.skip(1), 1);
+    assert_eq!(prod.push_iter(2..4), 2);
+
+    // Items wrap around.
+    assert_eq!(
+        (cons.get(0), cons.get(1), cons.get(2), cons.get(3)),
+        (Some(&1), Some(&2), Some(&3), None)
+    );
+    *cons.get_mut(2).unwrap() = 30;
+    assert_eq!(cons.get_mut(3), None);
+    assert!(cons.pop_iter().eq([1, 2, 30]));
+}
+
+#[test]
+fn index_local() {
+    let mut rb = crate::LocalRb::<Array<i32, 3>>::default();
+    rb.push_slice(&[0, 1, 2]);
+    rb.skip(1);
+    rb.try_push(3).unwrap();
+
+    assert_eq!(rb[0], 1);
+    assert_eq!(rb[2], 3);
+    rb[2] = 30;
+    assert!(rb.pop_iter().eq([1, 2, 30]));
+}
+
+#[test]
+#[should_panic]
+fn index_local_out_of_bounds() {
+    let mut rb = crate::LocalRb::<Array<i32, 3>>::default();
+    rb.push_slice(&[0, 1]);
+    let _ = rb[2];
+}
+
+#[test]
+fn eq() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 1, 2]);
+    rb.skip(2);
+    rb.push_slice(&[3, 4, 5]);
+    assert_eq!(rb.as_slices(), (&[2, 3][..], &[4, 5][..]));
+
+    assert_eq!(rb, [2, 3, 4, 5]);
+    assert_eq!(rb, &[2, 3, 4, 5][..]);
+    assert_ne!(rb, [2, 3, 4]);
+    assert_ne!(rb, [2, 3, 5, 4]);
+    #[cfg(feature = "alloc")]
+    assert_eq!(rb, alloc::vec![2, 3, 4, 5]);
+
+    let mut other = Rb::<Array<i32, 5>, u8>::default();
+    other.push_slice(&[2, 3, 4, 5]);
+    assert_eq!(rb, other);
+    other.try_push(6).unwrap();
+    assert_ne!(rb, other);
+}
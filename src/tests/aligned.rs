@@ -0,0 +1,42 @@
+use super::Rb;
+use crate::{storage::AlignedStorage, traits::*};
+use alloc::vec::Vec;
+
+#[test]
+fn alignment() {
+    let rb = Rb::<AlignedStorage<u8, 4096>>::new_aligned(3 * 4096);
+    assert_eq!(rb.capacity().get(), 3 * 4096);
+    assert_eq!(rb.vacant_slices().0.as_ptr() as usize % 4096, 0);
+
+    let mut rb = Rb::<AlignedStorage<u64, 64>>::new_aligned(5);
+    assert_eq!(rb.vacant_slices().0.as_ptr() as usize % 64, 0);
+    assert_eq!(rb.push_iter(0..), 5);
+    assert!(rb.pop_iter().eq(0..5));
+}
+
+#[test]
+fn small_align() {
+    let rb = Rb::<AlignedStorage<u64, 1>>::new_aligned(4);
+    assert_eq!(rb.as_slices().0.as_ptr() as usize % align_of::<u64>(), 0);
+}
+
+#[test]
+fn zero_sized() {
+    let mut rb = Rb::<AlignedStorage<(), 64>>::new_aligned(4);
+    assert_eq!(rb.push_iter(core::iter::repeat(())), 4);
+    assert_eq!(rb.skip(4), 4);
+}
+
+#[test]
+fn drop_items() {
+    let mut rb = Rb::<AlignedStorage<Vec<u8>, 64>>::new_aligned(4);
+    rb.try_push(Vec::from([1, 2, 3])).unwrap();
+    rb.try_push(Vec::from([4])).unwrap();
+    assert_eq!(rb.try_pop(), Some(Vec::from([1, 2, 3])));
+}
+
+#[test]
+#[should_panic]
+fn not_power_of_two() {
+    let _ = Rb::<AlignedStorage<u8, 48>>::new_aligned(4);
+}
@@ -0,0 +1,21 @@
+use super::Rb;
+use crate::{storage::Heap, traits::*};
+use arbitrary::{Arbitrary, Unstructured};
+
+#[test]
+fn arbitrary() {
+    let data = (0..=255).cycle().take(4096).collect::<alloc::vec::Vec<u8>>();
+    let mut u = Unstructured::new(&data);
+    let mut count = 0;
+    while let Ok(mut rb) = Rb::<Heap<u16>>::arbitrary(&mut u) {
+        assert!(rb.occupied_len() <= rb.capacity().get());
+        assert!(rb.read_index() < 2 * rb.capacity().get());
+        let items = rb.to_vec();
+        assert!(rb.pop_iter().eq(items));
+        count += 1;
+        if u.is_empty() {
+            break;
+        }
+    }
+    assert!(count > 1);
+}
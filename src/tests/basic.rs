@@ -165,3 +165,115 @@ fn len_remaining() {
     assert_eq!(prod.vacant_len(), 1);
     assert_eq!(cons.vacant_len(), 1);
 }
+
+#[test]
+fn occupancy() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let occupancy = prod.occupancy();
+    assert_eq!((occupancy.occupied, occupancy.vacant), (0, 3));
+    assert!(occupancy.is_empty() && !occupancy.is_full());
+
+    assert_eq!(prod.push_iter(0..2), 2);
+    let occupancy = cons.occupancy();
+    assert_eq!((occupancy.occupied, occupancy.vacant), (2, 1));
+    assert!(!occupancy.is_empty() && !occupancy.is_full());
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.push_iter(0..2), 2);
+    let occupancy = prod.occupancy();
+    assert_eq!((occupancy.occupied, occupancy.vacant), (3, 0));
+    assert!(!occupancy.is_empty() && occupancy.is_full());
+}
+
+#[test]
+fn relaxed() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let obs = prod.observe();
+
+    assert_eq!(prod.push_iter(0..2), 2);
+    assert_eq!(obs.occupied_len_relaxed(), 2);
+    assert_eq!(cons.vacant_len_relaxed(), 1);
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.push_iter(0..2), 2);
+    assert_eq!(prod.occupied_len_relaxed(), 3);
+    assert_eq!(obs.vacant_len_relaxed(), 0);
+    assert_eq!(obs.indices_relaxed(), (obs.read_index(), obs.write_index()));
+}
+
+#[test]
+fn relaxed_fenced() {
+    let mut rb = crate::FencedRb::<Array<i32, 3>>::default();
+    assert_eq!(rb.push_iter(0..2), 2);
+    assert_eq!(rb.occupied_len_relaxed(), 2);
+    assert_eq!(rb.vacant_len_relaxed(), 1);
+}
+
+#[test]
+fn push_with() {
+    let mut rb = Rb::<Array<[u8; 64], 2>>::default();
+    assert!(rb.try_push_with(|| [1; 64]).is_ok());
+    assert!(unsafe {
+        rb.try_push_init(|place| {
+            place.write([2; 64]);
+        })
+    }
+    .is_ok());
+
+    let mut called = false;
+    assert!(rb
+        .try_push_with(|| {
+            called = true;
+            [3; 64]
+        })
+        .is_err());
+    assert!(!called);
+
+    assert_eq!(rb.try_pop(), Some([1; 64]));
+    assert_eq!(rb.try_pop(), Some([2; 64]));
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn vacant_contiguous() {
+    let mut rb = Rb::<Array<i32, 6>>::default();
+    assert_eq!(rb.push_iter(0..5), 5);
+    assert_eq!(rb.skip(3), 3);
+    assert_eq!(rb.vacant_slices_mut().0.len(), 1);
+    assert!(rb.vacant_contiguous_mut(5).is_none());
+
+    // Enough contiguous space, items are not moved.
+    assert_eq!(rb.vacant_contiguous_mut(1).unwrap().len(), 1);
+    assert_eq!(rb.as_slices(), (&[3, 4][..], &[][..]));
+
+    let slice = rb.vacant_contiguous_mut(3).unwrap();
+    assert_eq!(slice.len(), 4);
+    for (i, place) in slice[..3].iter_mut().enumerate() {
+        place.write(5 + i as i32);
+    }
+    unsafe { rb.advance_write_index(3) };
+    assert_eq!(rb.as_slices(), (&[3, 4, 5, 6, 7][..], &[][..]));
+    #[cfg(feature = "counters")]
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (Some(8), Some(3)));
+}
+
+#[test]
+fn make_contiguous() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    assert_eq!(rb.push_iter(0..3), 3);
+    assert_eq!(rb.skip(3), 3);
+    assert_eq!(rb.push_iter(3..6), 3);
+    assert_eq!(rb.as_slices(), (&[3][..], &[4, 5][..]));
+
+    let slice = rb.make_contiguous();
+    assert_eq!(slice, [3, 4, 5]);
+    slice.reverse();
+    assert_eq!(rb.as_slices(), (&[5, 4, 3][..], &[][..]));
+    assert_eq!(rb.make_contiguous(), [5, 4, 3]);
+    #[cfg(feature = "counters")]
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (Some(6), Some(3)));
+    assert!(rb.pop_iter().eq([5, 4, 3]));
+}
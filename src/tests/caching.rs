@@ -0,0 +1,63 @@
+use super::Rb;
+use crate::{
+    storage::Array,
+    traits::*,
+    wrap::{CachingCons, CachingProd, Cons, Prod},
+};
+
+#[test]
+fn push_slice_fetch() {
+    let rb = Rb::<Array<i32, 4>>::default();
+    let mut prod = CachingProd::new(&rb);
+    let mut cons = Cons::new(&rb);
+
+    assert_eq!(prod.push_slice(&[0, 1]), 2);
+    assert_eq!(cons.pop_slice(&mut [0; 2]), 2);
+
+    // Enough vacant space is cached, so read index is not fetched.
+    assert_eq!(prod.push_slice(&[2, 3]), 2);
+    assert_eq!(prod.freeze().read_index(), 0);
+
+    let mut prod = CachingProd::new(&rb);
+    assert_eq!(cons.pop_slice(&mut [0; 2]), 2);
+    assert_eq!(prod.push_slice(&[4, 5]), 2);
+    assert_eq!(prod.push_slice(&[6, 7, 8]), 2);
+    assert!(cons.pop_iter().eq(4..8));
+}
+
+#[test]
+fn pop_slice_fetch() {
+    let rb = Rb::<Array<i32, 4>>::default();
+    let mut prod = Prod::new(&rb);
+    let mut cons = CachingCons::new(&rb);
+
+    assert_eq!(prod.push_slice(&[0, 1]), 2);
+    let mut buf = [0; 1];
+    assert_eq!(cons.pop_slice(&mut buf), 1);
+    assert_eq!(prod.push_slice(&[2]), 1);
+
+    // Enough items are cached, so write index is not fetched.
+    assert_eq!(cons.pop_slice(&mut buf), 1);
+    assert_eq!(buf, [1]);
+    assert_eq!(cons.freeze().write_index(), 2);
+
+    let mut cons = CachingCons::new(&rb);
+    let mut buf = [0; 2];
+    assert_eq!(cons.pop_slice(&mut buf), 1);
+    assert_eq!(buf[0], 2);
+}
+
+#[test]
+fn push_with_fetch() {
+    let rb = Rb::<Array<i32, 2>>::default();
+    let mut prod = CachingProd::new(&rb);
+    let mut cons = Cons::new(&rb);
+
+    assert!(prod.try_push_with(|| 0).is_ok());
+    assert!(prod.try_push_with(|| 1).is_ok());
+    assert!(prod.try_push_with(|| 2).is_err());
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert!(prod.try_push_with(|| 2).is_ok());
+    assert!(cons.pop_iter().eq(1..3));
+}
@@ -0,0 +1,64 @@
+use super::Rb;
+use crate::{
+    codec::{Error, TypedCons, TypedProd},
+    storage::Array,
+    traits::*,
+};
+use alloc::{string::String, vec::Vec};
+
+#[test]
+fn send_recv() {
+    let mut rb = Rb::<Array<u8, 16>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut prod, mut cons) = (TypedProd::new(prod), TypedCons::new(cons));
+
+    assert_eq!(cons.try_recv::<u32>(), Ok(None));
+    prod.try_send(&123u32).unwrap();
+    prod.try_send("abc").unwrap();
+    assert_eq!(cons.try_recv::<u32>(), Ok(Some(123)));
+    assert_eq!(cons.try_recv::<String>(), Ok(Some(String::from("abc"))));
+    assert_eq!(cons.try_recv::<String>(), Ok(None));
+}
+
+#[test]
+fn wrap_around() {
+    let mut rb = Rb::<Array<u8, 16>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut prod, mut cons) = (TypedProd::new(prod), TypedCons::new(cons));
+
+    for i in 0..32u8 {
+        let value = Vec::from([i; 5]);
+        prod.try_send(&value).unwrap();
+        assert_eq!(cons.try_recv::<Vec<u8>>(), Ok(Some(value)));
+    }
+}
+
+#[test]
+fn errors() {
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    let (prod, cons) = rb.split_ref();
+    let (mut prod, mut cons) = (TypedProd::new(prod), TypedCons::new(cons));
+
+    assert_eq!(prod.try_send(&[0u8; 4][..]), Err(Error::TooLarge));
+    prod.try_send(&[0xffu8; 2][..]).unwrap();
+    assert_eq!(prod.get_ref().occupied_len(), 7);
+    assert_eq!(prod.try_send(&()), Err(Error::Full));
+    assert!(matches!(cons.try_recv::<String>(), Err(Error::Postcard(_))));
+    assert!(cons.get_ref().is_empty());
+}
+
+#[test]
+fn partial_frame() {
+    let mut rb = Rb::<Array<u8, 16>>::default();
+    let (mut prod, cons) = rb.split_ref();
+    let mut cons = TypedCons::new(cons);
+
+    prod.push_slice(&[1, 0]);
+    assert_eq!(cons.try_recv::<u8>(), Ok(None));
+    prod.push_slice(&[0, 0]);
+    assert_eq!(cons.try_recv::<u8>(), Ok(None));
+    assert_eq!(cons.get_ref().occupied_len(), 4);
+    prod.push_slice(&[42]);
+    assert_eq!(cons.try_recv::<u8>(), Ok(Some(42)));
+    assert!(cons.get_ref().is_empty());
+}
@@ -0,0 +1,63 @@
+use super::Rb;
+use crate::{storage::Array, traits::*, FencedRb};
+
+fn check<R: RingBuffer<Item = i32>>(rb: &mut R) {
+    for i in 0..10 {
+        assert_eq!(rb.push_iter(0..3), 3);
+        assert_eq!(rb.total_pushed(), Some(3 * (i + 1)));
+        assert_eq!(rb.skip(2), 2);
+        assert_eq!(rb.try_pop(), Some(2));
+        assert_eq!(rb.total_popped(), Some(3 * (i + 1)));
+    }
+    assert_eq!(rb.push_iter(0..2), 2);
+    rb.push_slice_overwrite(&[0; 6]);
+    assert_eq!(rb.total_pushed(), Some(36));
+    assert_eq!(rb.total_popped(), Some(32));
+    assert_eq!(rb.total_pushed().unwrap() - rb.total_popped().unwrap(), rb.occupied_len() as u64);
+}
+
+#[test]
+fn totals() {
+    check(&mut Rb::<Array<i32, 4>>::default());
+    check(&mut FencedRb::<Array<i32, 4>>::default());
+}
+
+#[test]
+fn initial() {
+    let rb = Rb::from([1, 2, 3]);
+    assert_eq!(rb.total_pushed(), Some(3));
+    assert_eq!(rb.total_popped(), Some(0));
+}
+
+#[test]
+fn frozen() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (prod, mut cons) = rb.split_ref();
+    let mut prod = prod.freeze();
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(prod.total_pushed(), Some(3));
+    assert_eq!(cons.total_pushed(), Some(0));
+    prod.commit();
+    assert_eq!(cons.total_pushed(), Some(3));
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.total_popped(), Some(2));
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn no_wrap() {
+    fn check<R: RingBuffer<Item = ()>>(rb: &R) {
+        let capacity = rb.capacity().get();
+        for i in 1..4 {
+            unsafe { rb.advance_write_index(capacity) };
+            unsafe { rb.advance_read_index(capacity) };
+            assert_eq!(rb.total_pushed(), Some(i * capacity as u64));
+            assert_eq!(rb.total_popped(), Some(i * capacity as u64));
+        }
+    }
+
+    const CAP: usize = 1 << 40;
+    check(&Rb::<Array<(), CAP>>::default());
+    check(&FencedRb::<Array<(), CAP>>::default());
+}
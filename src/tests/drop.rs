@@ -106,3 +106,36 @@ fn transaction() {
     drop(rb);
     assert_eq!(set.borrow().len(), 0);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn clear_order_panic() {
+    use alloc::vec::Vec;
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct Item<'a> {
+        id: i32,
+        log: &'a RefCell<Vec<i32>>,
+    }
+    impl<'a> Drop for Item<'a> {
+        fn drop(&mut self) {
+            self.log.borrow_mut().push(self.id);
+            if self.id == 2 {
+                panic!("drop {}", self.id);
+            }
+        }
+    }
+
+    let log = RefCell::new(Vec::new());
+    let mut rb = Rb::<Array<Item, 4>>::default();
+    assert_eq!(rb.push_iter((0..2).map(|id| Item { id, log: &log })), 2);
+    assert_eq!(rb.skip(2), 2);
+    assert_eq!(rb.push_iter((1..5).map(|id| Item { id, log: &log })), 4);
+    log.borrow_mut().clear();
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| rb.clear())).is_err());
+    assert_eq!(*log.borrow(), [1, 2, 3, 4]);
+    assert!(rb.is_empty());
+    drop(rb);
+    assert_eq!(log.borrow().len(), 4);
+}
@@ -0,0 +1,103 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+use embedded_io::{ErrorKind, Read, ReadReady, Write, WriteReady};
+
+#[test]
+fn write_read() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut tmp = [0; 5];
+
+    assert!(!cons.read_ready().unwrap());
+    assert_eq!(prod.write(&[0, 1, 2, 3, 4]), Ok(4));
+    assert!(!prod.write_ready().unwrap());
+
+    assert!(cons.read_ready().unwrap());
+    assert_eq!(cons.read(&mut tmp[..3]), Ok(3));
+    assert_eq!(tmp[..3], [0, 1, 2]);
+
+    assert_eq!(prod.write(&[]), Ok(0));
+    assert!(prod.write_ready().unwrap());
+    assert_eq!(cons.read(&mut []), Ok(0));
+}
+
+#[test]
+fn closed() {
+    let mut rb = Rb::<Array<u8, 2>>::default();
+    {
+        let (mut prod, cons) = rb.split_ref();
+        drop(cons);
+        assert_eq!(prod.write(&[0, 1]), Ok(2));
+        assert_eq!(prod.write(&[2]), Err(ErrorKind::BrokenPipe));
+    }
+    {
+        let (prod, mut cons) = rb.split_ref();
+        drop(prod);
+        let mut tmp = [0; 3];
+        assert_eq!(cons.read(&mut tmp), Ok(2));
+        assert_eq!(cons.read(&mut tmp), Ok(0));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn blocking() {
+    use crate::{storage::Heap, SharedRb};
+    use std::{thread, vec::Vec};
+
+    const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    let rb = SharedRb::<Heap<u8>>::new(4);
+    let (mut prod, mut cons) = rb.split();
+
+    let pjh = thread::spawn(move || {
+        prod.write_all(MSG).unwrap();
+    });
+    let cjh = thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let mut tmp = [0; 3];
+        loop {
+            match cons.read(&mut tmp).unwrap() {
+                0 => break bytes,
+                n => bytes.extend_from_slice(&tmp[..n]),
+            }
+        }
+    });
+
+    pjh.join().unwrap();
+    assert_eq!(cjh.join().unwrap(), MSG);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn blocking_frozen() {
+    use crate::{
+        wrap::{FrozenCons, FrozenProd},
+        SharedRb,
+    };
+    use std::{thread, vec::Vec};
+
+    const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    let rb = SharedRb::<Array<u8, 4>>::default();
+
+    let bytes = thread::scope(|s| {
+        let (mut prod, mut cons) = (FrozenProd::new(&rb), FrozenCons::new(&rb));
+        s.spawn(move || {
+            prod.write_all(MSG).unwrap();
+            prod.flush().unwrap();
+        });
+        s.spawn(move || {
+            let mut bytes = Vec::new();
+            let mut tmp = [0; 3];
+            loop {
+                match cons.read(&mut tmp).unwrap() {
+                    0 => break bytes,
+                    n => bytes.extend_from_slice(&tmp[..n]),
+                }
+            }
+        })
+        .join()
+        .unwrap()
+    });
+    assert_eq!(bytes, MSG);
+}
@@ -0,0 +1,116 @@
+use crate::erased::{ErasedRb, TypedRb};
+use alloc::{rc::Rc, string::String};
+use core::alloc::Layout;
+
+#[test]
+fn push_pop() {
+    let mut rb = TypedRb::<u16>::new(3);
+    assert_eq!(rb.capacity(), 3);
+    assert!(rb.is_empty());
+
+    for i in 0..10 {
+        assert_eq!(rb.try_push(i), Ok(()));
+        assert_eq!(rb.try_push(i + 1), Ok(()));
+        assert_eq!(rb.occupied_len(), 2);
+        assert_eq!(rb.try_pop(), Some(i));
+        assert_eq!(rb.try_pop(), Some(i + 1));
+        assert_eq!(rb.try_pop(), None);
+    }
+
+    assert_eq!(rb.try_push(1), Ok(()));
+    assert_eq!(rb.try_push(2), Ok(()));
+    assert_eq!(rb.try_push(3), Ok(()));
+    assert!(rb.is_full());
+    assert_eq!(rb.vacant_len(), 0);
+    assert_eq!(rb.try_push(4), Err(4));
+}
+
+#[test]
+fn layout() {
+    let rb = ErasedRb::new_for::<(u32, u8)>(4);
+    assert_eq!(rb.item_layout(), Layout::new::<(u32, u8)>());
+
+    let rb = unsafe { ErasedRb::new(4, Layout::from_size_align(5, 4).unwrap(), None) };
+    assert_eq!(rb.item_layout().size(), 8);
+}
+
+#[test]
+fn zero_sized() {
+    let mut rb = TypedRb::<()>::new(2);
+    assert_eq!(rb.try_push(()), Ok(()));
+    assert_eq!(rb.try_push(()), Ok(()));
+    assert_eq!(rb.try_push(()), Err(()));
+    assert_eq!(rb.try_pop(), Some(()));
+}
+
+#[test]
+fn drop_items() {
+    let item = Rc::new(());
+    let mut rb = TypedRb::<Rc<()>>::new(4);
+    for _ in 0..4 {
+        rb.try_push(item.clone()).unwrap();
+    }
+    assert_eq!(Rc::strong_count(&item), 5);
+    assert_eq!(rb.try_push(item.clone()).map_err(drop), Err(()));
+    assert_eq!(Rc::strong_count(&item), 5);
+
+    drop(rb.try_pop());
+    assert_eq!(Rc::strong_count(&item), 4);
+    assert_eq!(rb.skip(1), 1);
+    assert_eq!(Rc::strong_count(&item), 3);
+
+    let rb = rb.into_erased();
+    assert_eq!(rb.occupied_len(), 2);
+    drop(rb);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+fn clear() {
+    let mut rb = TypedRb::<String>::new(3);
+    rb.try_push(String::from("a")).unwrap();
+    rb.try_push(String::from("b")).unwrap();
+    assert_eq!(rb.try_pop().as_deref(), Some("a"));
+    rb.try_push(String::from("c")).unwrap();
+    rb.try_push(String::from("d")).unwrap();
+    assert_eq!(rb.clear(), 3);
+    assert!(rb.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn skip_panic() {
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct Item<'a> {
+        id: i32,
+        log: &'a RefCell<Vec<i32>>,
+    }
+    impl<'a> Drop for Item<'a> {
+        fn drop(&mut self) {
+            self.log.borrow_mut().push(self.id);
+            if self.id == 2 {
+                panic!("drop {}", self.id);
+            }
+        }
+    }
+
+    let log = RefCell::new(Vec::new());
+    let mut rb = TypedRb::<Item>::new(4);
+    for id in 0..2 {
+        rb.try_push(Item { id, log: &log }).map_err(drop).unwrap();
+    }
+    assert_eq!(rb.skip(2), 2);
+    for id in 1..5 {
+        rb.try_push(Item { id, log: &log }).map_err(drop).unwrap();
+    }
+    log.borrow_mut().clear();
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| rb.skip(4))).is_err());
+    assert_eq!(*log.borrow(), [1, 2]);
+    assert_eq!(rb.occupied_len(), 2);
+    drop(rb);
+    assert_eq!(*log.borrow(), [1, 2, 3, 4]);
+}
@@ -0,0 +1,86 @@
+use crate::{storage::Array, traits::*, FencedRb};
+
+#[test]
+fn push_pop() {
+    let mut rb = FencedRb::<Array<i32, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.try_push(0), Ok(()));
+    assert_eq!(prod.try_push(1), Ok(()));
+    assert_eq!(prod.try_push(2), Err(2));
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(prod.try_push(2), Ok(()));
+    assert!(cons.pop_iter().eq(1..3));
+    assert_eq!(cons.try_pop(), None);
+}
+
+#[test]
+fn hold() {
+    let rb = FencedRb::<Array<i32, 2>, u8>::default();
+    {
+        let (_prod, _cons) = (&rb).split();
+        assert!(rb.write_is_held() && rb.read_is_held());
+    }
+    assert!(!rb.write_is_held() && !rb.read_is_held());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn concurrent() {
+    use std::{thread, vec::Vec};
+
+    const COUNT: usize = 1024;
+    let rb = FencedRb::<Array<usize, 7>, u8>::default();
+
+    let data = thread::scope(|s| {
+        let (mut prod, mut cons) = (&rb).split();
+        s.spawn(move || {
+            let mut iter = (0..COUNT).peekable();
+            while iter.peek().is_some() {
+                prod.push_iter(&mut iter);
+                thread::yield_now();
+            }
+        });
+        s.spawn(move || {
+            let mut data = Vec::new();
+            while data.len() < COUNT {
+                data.extend(cons.pop_iter());
+                thread::yield_now();
+            }
+            data
+        })
+        .join()
+        .unwrap()
+    });
+
+    assert!(data.into_iter().eq(0..COUNT));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hold_concurrent() {
+    use crate::wrap::Prod;
+    use std::{sync::Barrier, thread};
+
+    const THREADS: usize = 8;
+    let rb = FencedRb::<Array<i32, 2>>::default();
+    let barrier = Barrier::new(THREADS);
+
+    for _ in 0..64 {
+        let held = thread::scope(|s| {
+            let threads: [_; THREADS] = core::array::from_fn(|_| {
+                s.spawn(|| {
+                    barrier.wait();
+                    let prod = Prod::try_new(&rb).ok();
+                    // Keep producer until all threads have tried.
+                    barrier.wait();
+                    prod.is_some()
+                })
+            });
+            threads.map(|t| t.join().unwrap()).into_iter().filter(|held| *held).count()
+        });
+        assert_eq!(held, 1);
+        assert!(!rb.write_is_held());
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{ffi::*, traits::*, HeapCons};
+use alloc::boxed::Box;
+use core::ptr;
+
+#[test]
+fn push_pop() {
+    let (mut prod, mut cons) = (ptr::null_mut(), ptr::null_mut());
+    unsafe {
+        assert!(!ringbuf_create(0, &mut prod, &mut cons));
+        assert!(ringbuf_create(4, &mut prod, &mut cons));
+
+        assert_eq!(ringbuf_vacant_len(prod), 4);
+        assert_eq!(ringbuf_push(prod, [0, 1, 2, 3, 4].as_ptr(), 5), 4);
+        assert_eq!(ringbuf_push(prod, ptr::null(), 0), 0);
+        assert_eq!(ringbuf_occupied_len(cons), 4);
+
+        let mut buf = [0; 3];
+        assert_eq!(ringbuf_pop(cons, buf.as_mut_ptr(), buf.len()), 3);
+        assert_eq!(buf, [0, 1, 2]);
+        assert_eq!(ringbuf_vacant_len(prod), 3);
+
+        ringbuf_prod_destroy(prod);
+        let cons: Box<HeapCons<u8>> = Box::from_raw(cons);
+        assert!(!cons.write_is_held());
+        assert!(cons.into_iter().eq([3]));
+    }
+}
+
+#[test]
+fn null() {
+    unsafe {
+        assert_eq!(ringbuf_push(ptr::null_mut(), [0].as_ptr(), 1), 0);
+        assert_eq!(ringbuf_pop(ptr::null_mut(), [0].as_mut_ptr(), 1), 0);
+        assert_eq!(ringbuf_vacant_len(ptr::null()), 0);
+        assert_eq!(ringbuf_occupied_len(ptr::null()), 0);
+        ringbuf_prod_destroy(ptr::null_mut());
+        ringbuf_cons_destroy(ptr::null_mut());
+    }
+}
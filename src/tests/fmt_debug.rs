@@ -0,0 +1,36 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+use std::format;
+
+#[test]
+fn summary() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 1, 2]);
+    rb.skip(1);
+    assert!(format!("{:?}", rb).ends_with(" { capacity: 4, read_index: 1, write_index: 3, occupied_len: 2 }"));
+
+    let (prod, cons) = rb.split_ref();
+    assert!(format!("{:?}", prod).ends_with("Prod { capacity: 4, read_index: 1, write_index: 3, occupied_len: 2 }"));
+    assert_eq!(
+        format!("{:?}", cons.observe()),
+        "Obs { capacity: 4, read_index: 1, write_index: 3, occupied_len: 2 }"
+    );
+}
+
+#[test]
+fn contents() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 1, 2, 3]);
+    rb.skip(2);
+    rb.push_slice(&[4]);
+    assert_eq!(
+        format!("{:?}", rb.debug_contents()),
+        "DebugContents { capacity: 4, read_index: 2, write_index: 5, items: [2, 3, 4] }"
+    );
+
+    let (_, cons) = rb.split_ref();
+    assert_eq!(
+        format!("{:?}", cons.debug_contents()),
+        "DebugContents { capacity: 4, read_index: 2, write_index: 5, items: [2, 3, 4] }"
+    );
+}
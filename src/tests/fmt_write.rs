@@ -37,3 +37,19 @@ fn write_overflow() {
     assert_eq!(cons.occupied_len(), 10);
     assert!(cons.pop_iter().eq(b"This strin".iter().copied()));
 }
+
+#[test]
+fn write_handles() {
+    use crate::wrap::{CachingProd, FrozenProd};
+
+    let rb = Rb::<Array<u8, 16>>::default();
+    let mut prod = FrozenProd::new(&rb);
+    assert_eq!(write!(prod, "{}-", 1), Ok(()));
+    drop(prod);
+
+    let mut prod = CachingProd::new(&rb);
+    assert_eq!(write!(prod, "{}", 2), Ok(()));
+    drop(prod);
+
+    assert_eq!(rb.as_slices().0, b"1-2");
+}
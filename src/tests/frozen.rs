@@ -113,3 +113,25 @@ fn consumer() {
     assert_eq!(frozen_cons.occupied_len(), 1);
     assert_eq!(prod.occupied_len(), 1);
 }
+
+#[test]
+fn layout() {
+    use crate::wrap::{CachingProd, FrozenProd};
+    use core::mem::size_of;
+
+    let rb = Rb::<Array<i32, 4>>::default();
+    let mut prod = FrozenProd::new(&rb);
+    prod.try_push(0).unwrap();
+    prod.try_push(1).unwrap();
+
+    assert_eq!(size_of::<FrozenProd<&Rb<Array<i32, 4>>>>(), 3 * size_of::<usize>());
+    let raw = unsafe { &*(&prod as *const _ as *const [usize; 3]) };
+    assert_eq!(raw[0], &rb as *const _ as usize);
+    assert_eq!(raw[1..], [0, 2]);
+
+    drop(prod);
+    let prod = CachingProd::new(&rb);
+    assert_eq!(size_of::<CachingProd<&Rb<Array<i32, 4>>>>(), 3 * size_of::<usize>());
+    let raw = unsafe { &*(&prod as *const _ as *const [usize; 3]) };
+    assert_eq!(raw[1..], [0, 2]);
+}
@@ -0,0 +1,218 @@
+use super::Rb;
+use crate::{storage::Array, traits::*};
+use core::mem::MaybeUninit;
+
+#[test]
+fn write_read() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut grant = prod.write_grant();
+    assert_eq!(grant.len(), 4);
+    for (i, place) in grant.as_mut_slice()[..3].iter_mut().enumerate() {
+        *place = MaybeUninit::new(i as u8);
+    }
+    unsafe { grant.commit(3) };
+    assert_eq!(cons.occupied_len(), 3);
+
+    let grant = cons.read_grant();
+    assert_eq!(grant.as_slice(), [0, 1, 2]);
+    grant.release(2);
+    assert_eq!(cons.occupied_len(), 1);
+
+    // Grant covers only contiguous part of vacant memory.
+    assert_eq!(prod.write_grant().len(), 1);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+    assert!(prod.write_grant().is_empty());
+
+    assert_eq!(cons.read_grant().as_slice(), [2, 3]);
+    cons.read_grant().release(2);
+    assert_eq!(cons.read_grant().as_slice(), [4, 5]);
+}
+
+#[test]
+fn drop_without_commit() {
+    let mut rb = Rb::<Array<u8, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.write_grant().as_mut_slice()[0] = MaybeUninit::new(1);
+    assert!(cons.is_empty());
+
+    prod.try_push(2).unwrap();
+    assert_eq!(cons.read_grant().len(), 1);
+    assert_eq!(cons.try_pop(), Some(2));
+}
+
+#[test]
+#[should_panic]
+fn commit_overflow() {
+    let mut rb = Rb::<Array<u8, 2>>::default();
+    let (mut prod, _) = rb.split_ref();
+    unsafe { prod.write_grant().commit(3) };
+}
+
+#[cfg(feature = "embedded-dma")]
+#[test]
+fn dma_buffers() {
+    use embedded_dma::{ReadBuffer, WriteBuffer};
+
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut grant = prod.write_grant();
+    unsafe {
+        let (ptr, len) = grant.write_buffer();
+        assert_eq!(len, 4);
+        for i in 0..len {
+            ptr.add(i).write(i as u8);
+        }
+        grant.commit(len);
+    }
+
+    let grant = cons.read_grant();
+    unsafe {
+        let (ptr, len) = grant.read_buffer();
+        assert_eq!(core::slice::from_raw_parts(ptr, len), [0, 1, 2, 3]);
+    }
+    grant.release(4);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn write_exact() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert!(prod.write_grant_exact(5).is_none());
+    let mut grant = prod.write_grant_exact(3).unwrap();
+    assert_eq!(grant.len(), 3);
+    grant.as_mut_slice().fill(MaybeUninit::new(1));
+    unsafe { grant.commit(3) };
+
+    // Aborted on drop.
+    prod.write_grant_exact(1).unwrap().as_mut_slice().fill(MaybeUninit::new(2));
+    assert_eq!(cons.occupied_len(), 3);
+
+    assert_eq!(cons.skip(2), 2);
+    // Vacant space is split into two parts.
+    assert_eq!(prod.vacant_len(), 3);
+    assert!(prod.write_grant_exact(2).is_none());
+    assert_eq!(prod.write_grant_exact(1).unwrap().len(), 1);
+}
+
+#[test]
+fn reserve_out_of_order() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 0, 0]), 3);
+    assert_eq!(cons.skip(3), 3);
+
+    assert!(prod.reserve(5).is_none());
+    let mut writer = prod.reserve(3).unwrap();
+    assert_eq!(writer.len(), 3);
+    let mut slots = writer.slots();
+    let (first, second, third) = (slots.next().unwrap(), slots.next().unwrap(), slots.next().unwrap());
+    assert!(slots.next().is_none());
+    drop(slots);
+    third.write(2);
+    first.write(0);
+    second.write(1);
+    assert!(writer.is_complete());
+    assert!(writer.publish().is_ok());
+
+    // Reserved region wraps around the end of the storage.
+    assert_eq!(cons.as_slices(), (&[0][..], &[1, 2][..]));
+}
+
+#[test]
+fn reserve_incomplete() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, cons) = rb.split_ref();
+
+    let mut writer = prod.reserve(2).unwrap();
+    writer.slots().nth(1).unwrap().write(1);
+    assert_eq!(writer.completed(), 1);
+    let writer = writer.publish().err().unwrap();
+    drop(writer);
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.push_slice(&[2, 3]), 2);
+    assert_eq!(cons.as_slices().0, [2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn reserve_slots_twice() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, _) = rb.split_ref();
+    let mut writer = prod.reserve(2).unwrap();
+    let _ = writer.slots().count();
+    let _ = writer.slots().count();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn reserve_parallel() {
+    let mut rb = Rb::<Array<u64, 16>>::default();
+    let (mut prod, cons) = rb.split_ref();
+
+    let mut writer = prod.reserve(16).unwrap();
+    std::thread::scope(|s| {
+        for slot in writer.slots() {
+            s.spawn(move || {
+                let index = slot.index() as u64;
+                slot.write(index * index);
+            });
+        }
+    });
+    assert!(writer.publish().is_ok());
+    assert!(cons.iter().copied().eq((0..16).map(|i| i * i)));
+}
+
+#[test]
+fn peek_guard() {
+    let mut rb = Rb::<Array<u8, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert!(cons.peek_guard().is_none());
+
+    assert_eq!(prod.push_slice(&[1, 2]), 2);
+    {
+        let guard = cons.peek_guard().unwrap();
+        assert_eq!(*guard, 1);
+    }
+    // Item is not removed without commit.
+    assert_eq!(cons.occupied_len(), 2);
+
+    assert_eq!(cons.peek_guard().unwrap().commit(), 1);
+    assert_eq!(cons.peek_guard().unwrap().commit(), 2);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn cursor() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 0, 0]), 3);
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(prod.push_slice(&[1, 2, 3]), 3);
+
+    let mut cursor = cons.cursor();
+    assert_eq!(cursor.read(), Some(&1));
+    cursor.mark();
+    assert_eq!(cursor.read(), Some(&2));
+    assert_eq!(cursor.read(), Some(&3));
+    assert_eq!(cursor.read(), None);
+    assert_eq!(cursor.remaining(), 0);
+
+    cursor.reset();
+    assert_eq!(cursor.position(), 1);
+    assert_eq!(cursor.read(), Some(&2));
+
+    // Nothing is removed without advance.
+    assert_eq!(cons.occupied_len(), 3);
+
+    let mut cursor = cons.cursor();
+    assert_eq!(cursor.read(), Some(&1));
+    cursor.advance_to_cursor();
+    assert!(cons.pop_iter().eq([2, 3]));
+}
@@ -0,0 +1,37 @@
+use super::Rb;
+use crate::{
+    storage::{Array, Ref},
+    traits::*,
+};
+use core::mem::MaybeUninit;
+
+#[test]
+fn from_vec() {
+    let mut vec = heapless::Vec::<i32, 3>::new();
+    vec.push(123).unwrap();
+    vec.push(321).unwrap();
+    let mut rb = Rb::<Array<i32, 3>>::from(vec);
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.capacity().get(), 3);
+    assert_eq!(cons.occupied_len(), 2);
+
+    assert_eq!(prod.try_push(444), Ok(()));
+    assert_eq!(prod.try_push(555), Err(555));
+
+    assert_eq!(cons.try_pop(), Some(123));
+    assert_eq!(cons.try_pop(), Some(321));
+    assert_eq!(cons.try_pop(), Some(444));
+    assert_eq!(cons.try_pop(), None);
+}
+
+#[test]
+fn storage() {
+    let mut vec = heapless::Vec::<MaybeUninit<i32>, 2>::new();
+    let mut rb = unsafe { Rb::<Ref<i32>>::from_raw_parts(Ref::from(&mut vec), 0, 0) };
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.capacity().get(), 2);
+    assert_eq!(prod.push_iter(0..), 2);
+    assert!(cons.pop_iter().eq(0..2));
+}
@@ -1,5 +1,5 @@
 use super::Rb;
-use crate::{storage::Array, traits::*, CachingCons, CachingProd, Obs};
+use crate::{storage::Array, traits::*, CachingCons, CachingProd, Cons, Obs, Prod};
 
 #[test]
 fn split_and_drop() {
@@ -42,3 +42,150 @@ fn hold_conflict() {
     let _prod = CachingProd::new(&rb);
     CachingProd::new(&rb);
 }
+
+#[test]
+fn try_hold() {
+    let rb = Rb::<Array<i32, 2>>::default();
+    let obs = Obs::new(&rb);
+
+    let prod = CachingProd::try_new(&rb).ok().unwrap();
+    assert!(CachingProd::try_new(&rb).is_err());
+    assert!(Prod::try_new(&rb).is_err());
+    assert!(obs.write_is_held() && !obs.read_is_held());
+
+    let cons = Cons::try_new(&rb).ok().unwrap();
+    assert!(CachingCons::try_new(&rb).is_err());
+    assert!(obs.write_is_held() && obs.read_is_held());
+
+    drop(prod);
+    drop(cons);
+    assert!(!obs.write_is_held() && !obs.read_is_held());
+    assert!(CachingProd::try_new(&rb).is_ok());
+}
+
+#[test]
+fn push_checked() {
+    use crate::producer::PushError;
+
+    let mut rb = Rb::<Array<i32, 1>>::default();
+    let (mut prod, cons) = rb.split_ref();
+
+    assert_eq!(prod.try_push_checked(1), Ok(()));
+    assert_eq!(prod.try_push_checked(2), Err(PushError::Full(2)));
+
+    drop(cons);
+    let err = prod.try_push_checked(3).unwrap_err();
+    assert_eq!(err, PushError::Closed(3));
+    #[cfg(feature = "std")]
+    assert_eq!(
+        std::format!("{:?}: {}", err, err),
+        "Closed(..): consumer of the ring buffer is closed"
+    );
+    assert_eq!(err.into_inner(), 3);
+}
+
+#[test]
+fn pop_checked() {
+    use crate::consumer::PopError;
+
+    let mut rb = Rb::<Array<i32, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.try_pop_checked(), Err(PopError::Empty));
+    assert_eq!(prod.push_iter(0..2), 2);
+
+    drop(prod);
+    assert_eq!(cons.try_pop_checked(), Ok(0));
+    assert_eq!(cons.try_pop_checked(), Ok(1));
+    let err = cons.try_pop_checked().unwrap_err();
+    assert_eq!(err, PopError::Closed);
+    #[cfg(feature = "std")]
+    assert_eq!(std::format!("{:?}: {}", err, err), "Closed: producer of the ring buffer is closed");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn abandoned() {
+    use crate::{storage::Heap, wrap::Wrap};
+
+    let (mut prod, cons) = Rb::<Heap<i32>>::new(4).split();
+    prod.push_slice(&[0, 1]);
+    assert!(!prod.is_abandoned());
+    let mut prod = prod.try_into_rb().unwrap_err();
+    assert!(prod.write_is_held());
+    prod.try_push(2).unwrap();
+
+    let obs = cons.observe();
+    drop(cons);
+    assert!(!prod.is_abandoned());
+    drop(obs);
+    assert!(prod.is_abandoned());
+
+    let mut rb = prod.try_into_rb().unwrap();
+    assert!(!rb.write_is_held());
+    assert!(rb.pop_iter().eq([0, 1, 2]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn weak_observer() {
+    use crate::{storage::Heap, wrap::Wrap};
+    use alloc::rc::Rc;
+
+    let rc = Rc::new(());
+    let (mut prod, cons) = crate::SharedRb::<Heap<Rc<()>>>::new(4).split();
+    prod.try_push(rc.clone()).unwrap();
+    let weak = cons.observe().downgrade();
+    assert!(!cons.is_abandoned());
+
+    assert_eq!(weak.upgrade().unwrap().occupied_len(), 1);
+    drop(prod);
+    drop(cons);
+    assert!(weak.upgrade().is_none());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn custom_rb_ref() {
+    use crate::rb::RbRef;
+    use core::cell::Cell;
+
+    struct Counted<'a> {
+        rb: &'a Rb<Array<i32, 2>>,
+        count: &'a Cell<usize>,
+    }
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.count.set(self.count.get() + 1);
+            Self {
+                rb: self.rb,
+                count: self.count,
+            }
+        }
+    }
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.count.set(self.count.get() - 1);
+        }
+    }
+    impl AsRef<Rb<Array<i32, 2>>> for Counted<'_> {
+        fn as_ref(&self) -> &Rb<Array<i32, 2>> {
+            self.rb
+        }
+    }
+    unsafe impl RbRef for Counted<'_> {
+        type Rb = Rb<Array<i32, 2>>;
+    }
+
+    let rb = Rb::<Array<i32, 2>>::default();
+    let count = Cell::new(1);
+    let rb_ref = Counted { rb: &rb, count: &count };
+    let mut prod = Prod::new(rb_ref.clone());
+    let mut cons = Cons::new(rb_ref);
+    assert_eq!(count.get(), 2);
+
+    prod.try_push(1).unwrap();
+    assert_eq!(cons.try_pop(), Some(1));
+    drop((prod, cons));
+    assert_eq!(count.get(), 0);
+}
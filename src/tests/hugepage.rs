@@ -0,0 +1,62 @@
+use super::Rb;
+use crate::{storage::HugePage, traits::*};
+use std::{thread, vec::Vec};
+
+#[test]
+fn push_pop() {
+    const CAP: usize = 3 << 20;
+    let mut rb = Rb::<HugePage<u32>>::new_hugepage(CAP);
+    assert_eq!(rb.capacity().get(), CAP);
+
+    assert_eq!(rb.push_iter(0..), CAP);
+    assert!(rb.is_full());
+    assert!(rb.pop_iter().eq(0..(CAP as u32)));
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn zero_sized() {
+    let mut rb = Rb::<HugePage<()>>::new_hugepage(16);
+    assert_eq!(rb.push_iter(core::iter::repeat(())), 16);
+    assert_eq!(rb.skip(16), 16);
+}
+
+#[test]
+fn drop_items() {
+    let mut rb = Rb::<HugePage<Vec<u8>>>::new_hugepage(4);
+    rb.try_push(Vec::from([1, 2, 3])).unwrap();
+    rb.try_push(Vec::from([4])).unwrap();
+    assert_eq!(rb.try_pop(), Some(Vec::from([1, 2, 3])));
+}
+
+#[test]
+fn split() {
+    const COUNT: usize = 1 << 20;
+    let rb = crate::HugePageRb::<usize>::new_hugepage(1 << 16);
+    let (mut prod, mut cons) = rb.split();
+
+    let pjh = thread::spawn(move || {
+        let mut items = 0..COUNT;
+        while !items.is_empty() {
+            prod.push_iter(&mut items);
+        }
+    });
+    let mut next = 0;
+    while next < COUNT {
+        if let Some(item) = cons.try_pop() {
+            assert_eq!(item, next);
+            next += 1;
+        }
+    }
+    pjh.join().unwrap();
+}
+
+#[cfg(feature = "numa")]
+#[test]
+fn on_node() {
+    let mut rb = Rb::<HugePage<u8>>::new_on_node(1 << 20, 0);
+    assert_eq!(rb.push_slice(&[1, 2, 3]), 3);
+    assert_eq!(rb.try_pop(), Some(1));
+
+    assert!(Rb::<HugePage<u8>>::try_new_on_node(1 << 20, 1 << 20).is_err());
+}
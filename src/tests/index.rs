@@ -0,0 +1,121 @@
+use super::Rb;
+use crate::{rb::Index, storage::Array, traits::*, utils::uninit_array, LocalRb};
+use core::mem::size_of;
+
+#[test]
+fn narrow() {
+    const CAP: usize = 128;
+    let mut rb = Rb::<Array<u8, CAP>, u8>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for i in 0..(4 * CAP) {
+        assert_eq!(prod.push_iter((0..3).map(|j| (i + j) as u8)), 3);
+        assert!(prod.read_index() < 2 * CAP && prod.write_index() < 2 * CAP);
+        assert_eq!(cons.occupied_len(), 3);
+        assert!(cons.pop_iter().eq((0..3).map(|j| (i + j) as u8)));
+    }
+
+    assert_eq!(prod.push_iter((0..).map(|i| i as u8)), CAP);
+    assert!(prod.is_full());
+    assert!(cons.pop_iter().eq((0..CAP).map(|i| i as u8)));
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn max_capacity() {
+    assert_eq!(<u8 as Index>::MAX_CAPACITY, 128);
+    assert_eq!(<u16 as Index>::MAX_CAPACITY, 32768);
+    assert_eq!(<usize as Index>::MAX_CAPACITY, usize::MAX / 2);
+}
+
+#[test]
+fn narrow_u32() {
+    const CAP: usize = 100;
+    let mut rb = Rb::<Array<u8, CAP>, u32>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for i in 0..(4 * CAP) {
+        assert_eq!(prod.push_iter((0..3).map(|j| (i + j) as u8)), 3);
+        assert!(cons.pop_iter().eq((0..3).map(|j| (i + j) as u8)));
+    }
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(<u32 as Index>::MAX_CAPACITY, 1 << 31);
+    #[cfg(target_pointer_width = "64")]
+    assert!(size_of::<Rb<Array<u8, CAP>, u32>>() <= size_of::<Rb<Array<u8, CAP>>>());
+}
+
+#[test]
+#[should_panic]
+fn capacity_overflow() {
+    let _ = Rb::<Array<u8, 129>, u8>::default();
+}
+
+#[test]
+fn size() {
+    assert!(size_of::<LocalRb<Array<u8, 64>, u8>>() < size_of::<LocalRb<Array<u8, 64>>>());
+    // Cache padding makes indices of any type occupy the same space, and so does alignment of 64-bit counters.
+    #[cfg(not(any(feature = "cache_padding", feature = "counters")))]
+    assert!(size_of::<crate::SharedRb<Array<u8, 64>, u8>>() < size_of::<crate::SharedRb<Array<u8, 64>>>());
+}
+
+#[test]
+fn huge_capacity() {
+    const CAP: usize = usize::MAX / 2;
+    let mut rb = unsafe { Rb::<Array<(), CAP>>::from_raw_parts(uninit_array().into(), 2 * CAP - 2, CAP - 3) };
+    assert_eq!(rb.occupied_len(), CAP - 1);
+    assert_eq!(rb.vacant_len(), 1);
+
+    assert_eq!(rb.try_push(()), Ok(()));
+    assert!(rb.is_full());
+    assert_eq!(rb.write_index(), CAP - 2);
+    assert_eq!(rb.try_push(()), Err(()));
+
+    assert_eq!(rb.try_pop(), Some(()));
+    assert_eq!(rb.try_pop(), Some(()));
+    assert_eq!(rb.read_index(), 0);
+    assert_eq!(rb.occupied_len(), CAP - 2);
+
+    unsafe { rb.advance_read_index(CAP - 2) };
+    assert!(rb.is_empty());
+    assert_eq!(rb.read_index(), CAP - 2);
+    assert_eq!(rb.vacant_len(), CAP);
+
+    assert_eq!(rb.push_iter(core::iter::repeat_n((), 3)), 3);
+    assert_eq!(rb.write_index(), CAP + 1);
+    assert_eq!(rb.occupied_len(), 3);
+}
+
+#[test]
+fn power_of_two() {
+    const CAP: usize = 1 << (usize::BITS - 2);
+    let mut rb = unsafe { Rb::<Array<(), CAP>>::from_raw_parts(uninit_array().into(), 2 * CAP - 1, CAP - 2) };
+    assert_eq!(rb.occupied_len(), CAP - 1);
+    assert_eq!(rb.vacant_len(), 1);
+
+    assert_eq!(rb.try_push(()), Ok(()));
+    assert!(rb.is_full());
+    assert_eq!(rb.try_pop(), Some(()));
+    assert_eq!(rb.read_index(), 0);
+    assert_eq!(rb.as_slices().0.len(), CAP - 1);
+
+    unsafe { rb.advance_read_index(CAP - 1) };
+    assert!(rb.is_empty());
+    assert_eq!(rb.read_index(), CAP - 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn wrap_around() {
+    for cap in [1, 3, 4, 7, 8] {
+        let mut rb = Rb::<crate::storage::Heap<usize>>::new(cap);
+        let (mut prod, mut cons) = rb.split_ref();
+        for i in 0..(8 * cap) {
+            assert_eq!(prod.push_iter(i..(i + cap)), cap);
+            assert!(prod.read_index() < 2 * cap && prod.write_index() < 2 * cap);
+            let (left, right) = cons.as_slices();
+            assert_eq!(left.len() + right.len(), cap);
+            assert_eq!(cons.skip(cap - 1), cap - 1);
+            assert_eq!(cons.try_pop(), Some(i + cap - 1));
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use super::Rb;
 #[cfg(feature = "alloc")]
 use crate::storage::Heap;
-use crate::traits::*;
+use crate::{
+    storage::{Array, Ref},
+    traits::*,
+};
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, vec::Vec};
+use core::mem::MaybeUninit;
 
 #[test]
 fn from_array() {
@@ -73,3 +77,53 @@ fn from_boxed_slice() {
     assert_eq!(cons.try_pop(), Some(321));
     assert_eq!(cons.try_pop(), None);
 }
+
+#[test]
+fn in_place() {
+    let mut place = MaybeUninit::<Rb<Array<i32, 2>>>::uninit();
+    // Simulate memory that isn't zeroed at startup.
+    unsafe { place.as_mut_ptr().write_bytes(0xa5, 1) };
+    let rb = Rb::init_in_place(&mut place);
+    assert!(rb.is_empty());
+    assert!(!rb.read_is_held() && !rb.write_is_held());
+
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.capacity().get(), 2);
+    assert_eq!(prod.try_push(123), Ok(()));
+    assert_eq!(prod.try_push(321), Ok(()));
+    assert_eq!(prod.try_push(444), Err(444));
+
+    assert_eq!(cons.try_pop(), Some(123));
+    assert_eq!(cons.try_pop(), Some(321));
+    assert_eq!(cons.try_pop(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn from_iter() {
+    let rb = (0..3).collect::<Rb<Heap<i32>>>();
+    assert_eq!(rb.capacity().get(), 3);
+    assert!(rb.is_full());
+    assert_eq!(rb.to_vec(), [0, 1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn into_vec() {
+    let mut rb = Rb::from(Vec::from([0, 1, 2]));
+    assert_eq!(rb.try_pop(), Some(0));
+    assert_eq!(rb.try_push(3), Ok(()));
+    assert_eq!(rb.to_vec(), [1, 2, 3]);
+    assert_eq!(rb.into_vec(), [1, 2, 3]);
+}
+
+#[test]
+fn from_raw_memory() {
+    let mut memory = [MaybeUninit::<i32>::uninit(); 2];
+    let storage = unsafe { Ref::from_raw_parts(memory.as_mut_ptr(), memory.len()) };
+    let mut rb = unsafe { Rb::<Ref<i32>>::from_raw_parts(storage, 0, 0) };
+    assert_eq!(rb.capacity().get(), 2);
+
+    assert_eq!(rb.try_push(123), Ok(()));
+    assert_eq!(rb.try_pop(), Some(123));
+}
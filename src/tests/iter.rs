@@ -37,6 +37,23 @@ fn iter_mut() {
     assert_eq!(sum, first + second);
 }
 
+#[test]
+fn iter_wrap() {
+    let mut rb = Rb::<Array<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..2), 2);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(2..5), 3);
+    assert_ne!(cons.as_slices().1.len(), 0);
+
+    for v in cons.iter_mut() {
+        *v += 10;
+    }
+    assert!(cons.iter().copied().eq(12..15));
+    assert_eq!(cons.occupied_len(), 3);
+}
+
 #[test]
 fn pop_iter() {
     let mut rb = Rb::<Array<i32, 3>>::default();
@@ -77,3 +94,94 @@ fn push_pop_iter_partial() {
     assert_eq!(cons.try_pop().unwrap(), 5);
     assert!(prod.is_empty());
 }
+
+#[test]
+fn extend() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.extend([0, 1, 2]);
+    assert_eq!(prod.occupied_len(), 3);
+    prod.extend(3..);
+    assert!(prod.is_full());
+    assert!(cons.pop_iter().eq(0..4));
+}
+
+#[test]
+fn try_extend() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert!(prod.try_extend([0, 1]).is_ok());
+    assert!(prod.try_extend([2, 3]).is_ok());
+    assert!(prod.try_extend([]).is_ok());
+    assert!(cons.pop_iter().eq(0..4));
+
+    assert!(prod.try_extend(0..3).is_ok());
+    let rest = prod.try_extend(3..6).unwrap_err();
+    assert!(rest.eq(4..6));
+    assert!(cons.pop_iter().eq(0..4));
+}
+
+#[test]
+fn into_iter() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, cons) = rb.split_ref();
+
+    prod.push_iter(0..3);
+    // Ends when the ring buffer is empty even if producer exists.
+    assert!(cons.into_iter().eq(0..3));
+    assert!(prod.try_push(3).is_ok());
+}
+
+#[test]
+fn pop_while() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter([1, 2, 5, 3].into_iter()), 4);
+    assert!(cons.pop_while(|t| *t < 4).eq([1, 2]));
+    assert_eq!(cons.occupied_len(), 2);
+
+    // Items not taken from the iterator stay in the ring buffer.
+    assert_eq!(cons.pop_while(|_| true).next(), Some(5));
+    assert!(cons.pop_iter().eq([3]));
+    assert_eq!(cons.pop_while(|_| true).next(), None);
+}
+
+#[test]
+fn pop_each() {
+    use core::ops::ControlFlow;
+
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter(0..2), 2);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(2..6), 4);
+
+    let mut sum = 0;
+    let count = cons.pop_each(|v| {
+        sum += v;
+        if sum < 5 {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    assert_eq!((count, sum), (2, 5));
+    assert_eq!(cons.pop_each(|_| ControlFlow::Continue(())), 2);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn pop_chunks() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.push_iter(0..7), 7);
+
+    // Chunks wrap around.
+    assert!(cons.pop_chunks::<3>().eq([[0, 1, 2], [3, 4, 5]]));
+    assert!(cons.pop_iter().eq([6]));
+}
@@ -0,0 +1,35 @@
+use super::Rb;
+use crate::{logger::RbLogger, storage::Array, traits::*};
+use log::{Level, LevelFilter, Log, Record};
+
+#[test]
+fn log() {
+    let logger = RbLogger::new(Rb::<Array<u8, 32>>::default(), LevelFilter::Info);
+    logger.log(&Record::builder().level(Level::Info).target("a").args(format_args!("one")).build());
+    logger.log(
+        &Record::builder()
+            .level(Level::Debug)
+            .target("a")
+            .args(format_args!("skipped"))
+            .build(),
+    );
+    logger.log(&Record::builder().level(Level::Warn).target("b").args(format_args!("{}", 2)).build());
+    assert_eq!(logger.drain(), b"[INFO a] one\n[WARN b] 2\n");
+    assert!(logger.lock().is_empty());
+}
+
+#[test]
+fn overwrite() {
+    let logger = RbLogger::new(Rb::<Array<u8, 16>>::default(), LevelFilter::Trace);
+    for i in 0..4 {
+        logger.log(
+            &Record::builder()
+                .level(Level::Error)
+                .target("t")
+                .args(format_args!("{}", i))
+                .build(),
+        );
+    }
+    assert!(logger.lock().is_full());
+    assert_eq!(logger.drain(), b"] 2\n[ERROR t] 3\n");
+}
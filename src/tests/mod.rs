@@ -4,21 +4,54 @@ use crate::LocalRb as Rb;
 use crate::SharedRb as Rb;
 
 mod access;
+#[cfg(feature = "alloc")]
+mod aligned;
+#[cfg(all(feature = "alloc", feature = "arbitrary"))]
+mod arbitrary;
 mod basic;
+mod caching;
+#[cfg(feature = "codec")]
+mod codec;
+#[cfg(feature = "counters")]
+mod counters;
 #[cfg(feature = "alloc")]
 mod drop;
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+#[cfg(feature = "alloc")]
+mod erased;
+mod fenced;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "std")]
+mod fmt_debug;
 mod fmt_write;
 mod frozen;
+mod grant;
+#[cfg(feature = "heapless")]
+mod heapless;
 mod hold;
+#[cfg(all(feature = "hugepage", target_os = "linux"))]
+mod hugepage;
+mod index;
 mod init;
 mod iter;
+#[cfg(feature = "log")]
+mod logger;
 mod new;
 mod overwrite;
 #[cfg(feature = "std")]
 mod read_write;
+#[cfg(feature = "alloc")]
+mod resize;
 #[cfg(feature = "std")]
 mod shared;
 #[cfg(feature = "alloc")]
 mod skip;
 mod slice;
+#[cfg(feature = "alloc")]
+mod snapshot;
+mod split_once;
 mod unsized_;
+#[cfg(feature = "alloc")]
+mod zst;
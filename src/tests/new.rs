@@ -43,3 +43,14 @@ fn from_vec() {
     assert_eq!(cons.try_pop(), Some(5));
     assert_eq!(cons.try_pop(), None);
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn try_new_capacity() {
+    use crate::{rb::CapacityError, storage::Heap};
+
+    assert_eq!(Rb::<Heap<i32>>::try_new(0).unwrap_err(), CapacityError::Zero);
+    assert_eq!(Rb::<Heap<i32>>::try_new(usize::MAX / 2 + 1).unwrap_err(), CapacityError::TooLarge);
+    assert!(matches!(Rb::<Heap<i32>>::try_new(usize::MAX / 2), Err(CapacityError::Alloc(_))));
+    assert_eq!(Rb::<Heap<i32>>::try_new(3).unwrap().capacity().get(), 3);
+}
@@ -82,6 +82,49 @@ fn into() {
     assert_eq!(tmp[0..4], [6, 7, 8, 9]);
 }
 
+#[cfg(feature = "read_buf")]
+#[test]
+fn read_buf() {
+    use core::mem::MaybeUninit;
+    use std::{io::BorrowedBuf, vec::Vec};
+
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut buf = [MaybeUninit::uninit(); 8];
+    let mut buf = BorrowedBuf::from(&mut buf[..]);
+    cons.read_buf(buf.unfilled()).unwrap();
+    assert_eq!(buf.filled(), [2, 3, 4, 5]);
+    assert_eq!(cons.read_buf(buf.unfilled()).map_err(|e| e.kind()), Err(io::ErrorKind::WouldBlock));
+
+    // Reads into spare capacity of a vector without zeroing it.
+    assert_eq!(prod.push_slice(&[6, 7]), 2);
+    let mut vec = Vec::with_capacity(4);
+    cons.read_to_end(&mut vec).unwrap_err();
+    assert_eq!(vec, [6, 7]);
+}
+
+#[test]
+fn write_into_vectored() {
+    use std::vec::Vec;
+
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut dst = Vec::new();
+    assert_eq_kind!(cons.write_into_vectored(&mut dst, Some(3)), Some(Ok(3)));
+    assert_eq!(dst, [2, 3, 4]);
+    assert_eq_kind!(cons.write_into_vectored(&mut dst, None), Some(Ok(1)));
+    assert_eq!(dst, [2, 3, 4, 5]);
+    assert_eq_kind!(cons.write_into_vectored(&mut dst, None), None);
+}
+
 #[test]
 fn count() {
     let mut rb0 = Rb::<Array<u8, 4>>::default();
@@ -132,3 +175,93 @@ fn read_from() {
 
     assert!(cons.pop_iter().eq([2; 3]));
 }
+
+#[test]
+fn vectored() {
+    use std::{io::Write, vec::Vec};
+
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+
+    let mut src: &[u8] = &[3, 4, 5, 6, 7];
+    let n = src.read_vectored(&mut prod.vacant_ioslices_mut()).unwrap();
+    assert_eq!(n, 3);
+    unsafe { prod.advance_write_index(n) };
+    assert!(prod.is_full());
+
+    let mut dst = Vec::new();
+    let n = dst.write_vectored(&cons.occupied_ioslices()).unwrap();
+    assert_eq!(n, 4);
+    assert_eq!(cons.skip(n), 4);
+    assert_eq!(dst, [2, 3, 4, 5]);
+}
+
+#[test]
+fn buf_read() {
+    use std::{io::BufRead, string::String};
+
+    let mut rb = Rb::<Array<u8, 16>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.fill_buf().map_err(|e| e.kind()), Err(io::ErrorKind::WouldBlock));
+
+    assert_eq!(prod.push_slice(b"hello\nworld"), 11);
+    let mut line = String::new();
+    assert_eq!(cons.read_line(&mut line).unwrap(), 6);
+    assert_eq!(line, "hello\n");
+
+    drop(prod);
+    line.clear();
+    assert_eq!(cons.read_line(&mut line).unwrap(), 5);
+    assert_eq!(line, "world");
+    assert!(cons.fill_buf().unwrap().is_empty());
+}
+
+#[test]
+fn buf_read_until_wrapped() {
+    use std::{io::BufRead, vec::Vec};
+
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(b"xxxxx"), 5);
+    assert_eq!(cons.skip(5), 5);
+    assert_eq!(prod.push_slice(b"abc;de;"), 7);
+
+    // Delimiter is located in the second occupied slice.
+    let mut buf = Vec::new();
+    assert_eq!(cons.read_until(b';', &mut buf).unwrap(), 4);
+    assert_eq!(buf, b"abc;");
+    buf.clear();
+    assert_eq!(cons.read_until(b';', &mut buf).unwrap(), 3);
+    assert_eq!(buf, b"de;");
+}
+
+#[test]
+fn buf_read_rb() {
+    use std::io::BufRead;
+
+    let mut rb = Rb::<Array<u8, 8>>::default();
+    assert_eq!(rb.push_slice(b"ab\ncd"), 5);
+    assert!((&mut rb).lines().map(Result::unwrap).eq(["ab", "cd"]));
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn split_handles() {
+    use crate::HeapRb;
+    use std::{io::Write, thread};
+
+    fn write_to(writer: &mut impl Write) {
+        writer.write_all(b"hello").unwrap();
+    }
+    fn read_from(reader: &mut impl Read) -> [u8; 5] {
+        let mut buf = [0; 5];
+        reader.read_exact(&mut buf).unwrap();
+        buf
+    }
+
+    let (mut prod, mut cons) = HeapRb::<u8>::new(8).split();
+    thread::spawn(move || write_to(&mut prod)).join().unwrap();
+    assert_eq!(&read_from(&mut cons), b"hello");
+}
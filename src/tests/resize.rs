@@ -0,0 +1,75 @@
+use crate::{traits::*, HeapRb};
+use alloc::string::String;
+
+#[test]
+fn grow() {
+    let mut rb = HeapRb::<String>::new(3);
+    rb.push_iter(["a", "b", "c"].map(String::from).into_iter());
+    assert_eq!(rb.skip(2), 2);
+    rb.push_iter(["d", "e"].map(String::from).into_iter());
+    assert!(rb.is_full());
+
+    rb.grow(2);
+    assert_eq!(rb.capacity().get(), 3);
+
+    rb.grow(5);
+    assert_eq!(rb.capacity().get(), 5);
+    assert_eq!(rb.as_slices().0, ["c", "d", "e"]);
+    #[cfg(feature = "counters")]
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (Some(5), Some(2)));
+    rb.push_iter(["f", "g"].map(String::from).into_iter());
+    assert!(rb.pop_iter().eq(["c", "d", "e", "f", "g"]));
+}
+
+#[test]
+fn try_reserve() {
+    let mut rb = HeapRb::<i32>::new(4);
+    rb.push_slice(&[0, 1, 2]);
+    rb.try_reserve(1).unwrap();
+    assert_eq!(rb.capacity().get(), 4);
+    rb.try_reserve(2).unwrap();
+    assert_eq!(rb.capacity().get(), 8);
+    rb.try_reserve(6).unwrap();
+    assert_eq!(rb.capacity().get(), 16);
+    rb.try_reserve(30).unwrap();
+    assert_eq!(rb.capacity().get(), 33);
+    assert!(rb.try_reserve(usize::MAX).is_err());
+    assert_eq!(rb, [0, 1, 2]);
+}
+
+#[test]
+fn shrink() {
+    let mut rb = HeapRb::<String>::new(8);
+    rb.push_iter(["a", "b", "c", "d", "e", "f", "g"].map(String::from).into_iter());
+    assert_eq!(rb.skip(4), 4);
+    rb.push_iter(["h", "i"].map(String::from).into_iter());
+    assert_eq!(rb.occupied_len(), 5);
+
+    rb.shrink_to(6);
+    assert_eq!(rb.capacity().get(), 6);
+    rb.shrink_to(7);
+    assert_eq!(rb.capacity().get(), 6);
+    rb.shrink_to_fit();
+    assert_eq!(rb.capacity().get(), 5);
+    assert!(rb.is_full());
+    #[cfg(feature = "counters")]
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (Some(9), Some(4)));
+    assert!(rb.iter().eq(["e", "f", "g", "h", "i"]));
+
+    rb.clear();
+    rb.shrink_to_fit();
+    assert_eq!(rb.capacity().get(), 1);
+}
+
+#[test]
+fn resize_split() {
+    let (mut prod, cons) = HeapRb::<i32>::new(2).split();
+    prod.push_slice(&[0, 1]);
+    assert!(prod.is_full());
+
+    let mut rb = HeapRb::try_unsplit(prod, cons).unwrap();
+    rb.grow(4);
+    let (mut prod, mut cons) = rb.split();
+    prod.push_slice(&[2, 3]);
+    assert!(cons.pop_iter().eq(0..4));
+}
@@ -1,4 +1,8 @@
-use crate::{storage::Heap, traits::*, SharedRb};
+use crate::{
+    storage::{Array, Heap},
+    traits::*,
+    HeapRb, SharedRb,
+};
 use std::{thread, thread::sleep, time::Duration, vec::Vec};
 
 #[cfg(feature = "std")]
@@ -34,3 +38,118 @@ fn concurrent() {
     pjh.join().unwrap();
     assert_eq!(cjh.join().unwrap(), MSG);
 }
+
+#[test]
+fn split_by_ref() {
+    const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    let rb = SharedRb::<Array<u8, 4>>::default();
+
+    let msg = thread::scope(|s| {
+        let (mut prod, mut cons) = (&rb).split();
+
+        s.spawn(move || {
+            let mut msg = MSG;
+            while !msg.is_empty() {
+                let n = prod.push_slice(msg);
+                msg = &msg[n..];
+                thread::yield_now();
+            }
+        });
+
+        s.spawn(move || {
+            let mut msg = Vec::new();
+            while msg.len() < MSG.len() {
+                msg.extend(cons.pop_iter());
+                thread::yield_now();
+            }
+            msg
+        })
+        .join()
+        .unwrap()
+    });
+
+    assert_eq!(msg, MSG);
+    assert!(!rb.write_is_held() && !rb.read_is_held());
+}
+
+#[test]
+#[should_panic]
+fn split_by_ref_twice() {
+    let rb = SharedRb::<Array<u8, 4>>::default();
+    let _handles = (&rb).split();
+    let _ = (&rb).split();
+}
+
+#[test]
+fn padding() {
+    use core::mem::size_of;
+
+    let size = size_of::<SharedRb<Array<u8, 4>, u8>>();
+    #[cfg(feature = "cache_padding")]
+    assert!(size >= 2 * size_of::<crossbeam_utils::CachePadded<u8>>());
+    #[cfg(all(not(feature = "cache_padding"), not(feature = "counters")))]
+    assert_eq!(size, 8);
+    #[cfg(all(not(feature = "cache_padding"), feature = "counters"))]
+    assert_eq!(size, 8 + 4 * size_of::<u64>());
+}
+
+#[test]
+fn into_iter() {
+    const COUNT: usize = 1024;
+    let rb = SharedRb::<Array<usize, 4>>::default();
+    let (mut prod, cons) = rb.split();
+
+    let pjh = thread::spawn(move || {
+        let mut items = 0..COUNT;
+        while !items.is_empty() {
+            prod.push_iter(&mut items);
+            thread::yield_now();
+        }
+    });
+
+    assert!(cons.into_drain_iter().eq(0..COUNT));
+    pjh.join().unwrap();
+}
+
+#[test]
+fn unsplit() {
+    let (mut prod, cons) = HeapRb::<i32>::new(4).split();
+    prod.push_slice(&[0, 1]);
+
+    let obs = cons.observe();
+    let (prod, cons) = HeapRb::try_unsplit(prod, cons).unwrap_err();
+    drop(obs);
+
+    let (other_prod, other_cons) = HeapRb::<i32>::new(4).split();
+    let (mut prod, other_cons) = HeapRb::try_unsplit(prod, other_cons).unwrap_err();
+    assert!(HeapRb::try_unsplit(other_prod, other_cons).is_ok());
+
+    // Cached push is committed on unsplit.
+    prod.try_push(2).unwrap();
+    let mut rb = HeapRb::try_unsplit(prod, cons).unwrap();
+    assert!(!rb.read_is_held() && !rb.write_is_held());
+    assert!(rb.pop_iter().eq([0, 1, 2]));
+
+    let (_prod, _cons) = rb.split();
+}
+
+#[test]
+fn unsplit_wrappers() {
+    use crate::{wrap::Wrap, Cons};
+
+    let (prod, cons) = HeapRb::<i32>::new(4).split();
+    let mut prod = prod.freeze();
+    let cons = Cons::new(cons.into_rb_ref());
+    prod.try_push(0).unwrap();
+
+    let obs = cons.observe();
+    let (mut prod, cons) = HeapRb::try_unsplit(prod, cons).unwrap_err();
+    assert!(obs.write_is_held() && obs.read_is_held());
+    drop(obs);
+
+    // Frozen push is committed on unsplit.
+    prod.try_push(1).unwrap();
+    let mut rb = HeapRb::try_unsplit(prod, cons).unwrap();
+    assert!(!rb.read_is_held() && !rb.write_is_held());
+    assert!(rb.pop_iter().eq([0, 1]));
+}
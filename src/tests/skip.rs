@@ -1,6 +1,7 @@
 use super::Rb;
 use crate::{storage::Array, traits::*};
 use alloc::rc::Rc;
+use core::mem;
 
 #[test]
 fn skip() {
@@ -70,3 +71,165 @@ fn skip_drop() {
     // Check that items are dropped
     assert_eq!(Rc::strong_count(&rc), 1);
 }
+
+#[test]
+fn skip_while() {
+    let rc = Rc::<()>::new(());
+    let mut rb = Rb::<Array<(i32, Rc<()>), 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter((0..2).map(|i| (i, rc.clone()))), 2);
+    assert_eq!(cons.skip(1), 1);
+    assert_eq!(prod.push_iter((2..5).map(|i| (i, rc.clone()))), 3);
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    assert_eq!(cons.skip_while(|(i, _)| *i < 4), 3);
+    assert_eq!(Rc::strong_count(&rc), 2);
+    assert_eq!(cons.skip_while(|(i, _)| *i < 4), 0);
+    assert_eq!(cons.first().map(|(i, _)| *i), Some(4));
+    assert_eq!(cons.skip_while(|_| true), 1);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn retain() {
+    let rc = Rc::<()>::new(());
+    let mut rb = Rb::<Array<(i32, Rc<()>), 8>>::default();
+    assert_eq!(rb.push_iter((0..4).map(|i| (i, rc.clone()))), 4);
+    assert_eq!(rb.skip(4), 4);
+    assert_eq!(rb.push_iter((0..8).map(|i| (i, rc.clone()))), 8);
+
+    // Items wrap around.
+    rb.retain(|(i, _)| i % 3 != 0);
+    assert_eq!(Rc::strong_count(&rc), 6);
+    assert!(rb.iter().map(|(i, _)| *i).eq([1, 2, 4, 5, 7]));
+
+    rb.retain_mut(|(i, _)| {
+        *i *= 10;
+        *i != 40
+    });
+    assert!(rb.iter().map(|(i, _)| *i).eq([10, 20, 50, 70]));
+    assert_eq!(Rc::strong_count(&rc), 5);
+    assert_eq!(rb.push_iter((0..4).map(|i| (i, rc.clone()))), 4);
+    assert!(rb.pop_iter().map(|(i, _)| i).eq([10, 20, 50, 70, 0, 1, 2, 3]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn retain_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let rc = Rc::<()>::new(());
+    let mut rb = Rb::<Array<(i32, Rc<()>), 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter((0..6).map(|i| (i, rc.clone()))), 6);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        cons.retain(|(i, _)| match i {
+            2 => panic!(),
+            _ => i % 2 == 0,
+        })
+    }));
+    assert!(result.is_err());
+    // Items are processed from the eldest one, so only the items before the panicked one are removed.
+    assert_eq!(Rc::strong_count(&rc), 6);
+    assert!(cons.pop_iter().map(|(i, _)| i).eq([0, 2, 3, 4, 5]));
+}
+
+#[test]
+fn retain_order() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    assert_eq!(rb.push_iter(0..5), 5);
+    assert_eq!(rb.skip(5), 5);
+    assert_eq!(rb.push_iter(0..8), 8);
+
+    let mut visited = [0; 8];
+    let mut count = 0;
+    rb.retain(|i| {
+        visited[count] = *i;
+        count += 1;
+        i % 2 == 1
+    });
+    assert_eq!(count, 8);
+    assert_eq!(visited, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert!(rb.iter().copied().eq([1, 3, 5, 7]));
+}
+
+#[test]
+fn truncate() {
+    let rc = Rc::<()>::new(());
+    let mut rb = Rb::<Array<(i32, Rc<()>), 8>>::default();
+    assert_eq!(rb.push_iter((0..4).map(|i| (i, rc.clone()))), 4);
+    assert_eq!(rb.skip(4), 4);
+    assert_eq!(rb.push_iter((0..8).map(|i| (i, rc.clone()))), 8);
+
+    assert_eq!(rb.truncate_front(6), 2);
+    assert_eq!(Rc::strong_count(&rc), 7);
+    assert!(rb.iter().map(|(i, _)| *i).eq(2..8));
+
+    // Items wrap around.
+    assert_eq!(rb.truncate_back(3), 3);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    assert!(rb.iter().map(|(i, _)| *i).eq(2..5));
+
+    assert_eq!(rb.truncate_back(3), 0);
+    assert_eq!(rb.truncate_front(4), 0);
+    assert_eq!(rb.truncate_back(0), 3);
+    assert!(rb.is_empty());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn drain() {
+    let rc = Rc::<()>::new(());
+    let mut rb = Rb::<Array<(i32, Rc<()>), 8>>::default();
+    assert_eq!(rb.push_iter((0..4).map(|i| (i, rc.clone()))), 4);
+    assert_eq!(rb.skip(4), 4);
+    assert_eq!(rb.push_iter((0..8).map(|i| (i, rc.clone()))), 8);
+
+    assert!(rb.drain(2..5).map(|(i, _)| i).eq(2..5));
+    assert_eq!(Rc::strong_count(&rc), 6);
+    assert!(rb.iter().map(|(i, _)| *i).eq([0, 1, 5, 6, 7]));
+
+    {
+        let mut drain = rb.drain(1..=3);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next_back().unwrap().0, 6);
+    }
+    assert_eq!(Rc::strong_count(&rc), 3);
+    assert!(rb.iter().map(|(i, _)| *i).eq([0, 7]));
+
+    assert_eq!(rb.drain(..0).count(), 0);
+    assert_eq!(rb.drain(2..).count(), 0);
+    assert_eq!(rb.occupied_len(), 2);
+    assert_eq!(rb.drain(..).count(), 2);
+    assert!(rb.is_empty());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn drain_forget() {
+    use alloc::string::{String, ToString};
+
+    let mut rb = Rb::<Array<String, 8>>::default();
+    assert_eq!(rb.push_iter((0..4).map(|i| i.to_string())), 4);
+    assert_eq!(rb.skip(4), 4);
+    assert_eq!(rb.push_iter((0..8).map(|i| i.to_string())), 8);
+
+    let mut drain = rb.drain(2..6);
+    assert_eq!(drain.next().as_deref(), Some("2"));
+    assert_eq!(drain.next_back().as_deref(), Some("5"));
+    // Items remaining in the iterator are leaked, taken ones are not dropped twice.
+    mem::forget(drain);
+    assert!(rb.iter().map(String::as_str).eq(["0", "1", "6", "7"]));
+    assert_eq!(rb.push_iter((8..12).map(|i| i.to_string())), 4);
+    assert!(rb.pop_iter().eq(["0", "1", "6", "7", "8", "9", "10", "11"]));
+}
+
+#[test]
+#[should_panic]
+fn drain_out_of_bounds() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    rb.push_slice(&[0, 1]);
+    rb.drain(1..3);
+}
@@ -26,6 +26,30 @@ fn push_pop_slice() {
     assert_eq!(tmp[0..4], [5, 6, 7, 8]);
 }
 
+#[test]
+fn push_pop_slice_unchecked() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut tmp = [0; 4];
+    unsafe {
+        prod.push_slice_unchecked(&[]);
+        prod.push_slice_unchecked(&[0, 1, 2]);
+        assert_eq!(prod.vacant_len(), 1);
+
+        cons.pop_slice_unchecked(&mut tmp[0..2]);
+        assert_eq!(tmp[0..2], [0, 1]);
+
+        // Wraps around.
+        prod.push_slice_unchecked(&[3, 4, 5]);
+        assert!(prod.is_full());
+
+        cons.pop_slice_unchecked(&mut tmp);
+        assert_eq!(tmp, [2, 3, 4, 5]);
+        assert!(cons.is_empty());
+    }
+}
+
 #[test]
 fn move_slice() {
     let mut rb0 = Rb::<Array<i32, 4>>::default();
@@ -95,3 +119,211 @@ fn move_slice_count() {
     assert_eq!(cons1.pop_slice(&mut tmp), 4);
     assert_eq!(tmp[0..4], [6, 7, 8, 9]);
 }
+
+#[test]
+fn frames() {
+    let mut rb = Rb::<Array<f32, 7>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_frames(&[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]]), 3);
+    assert_eq!(prod.occupied_len(), 6);
+    assert_eq!(prod.try_push(-1.0), Ok(()));
+
+    let mut frames = [[0.0; 2]; 4];
+    assert_eq!(cons.pop_frames(&mut frames), 3);
+    assert_eq!(frames[..3], [[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+    assert_eq!(cons.pop_frames(&mut frames), 0);
+    assert_eq!(cons.occupied_len(), 1);
+}
+
+#[test]
+fn planar() {
+    let mut rb = Rb::<Array<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0; 5]), 5);
+    assert_eq!(cons.skip(5), 5);
+
+    assert_eq!(prod.push_planar(&[&[0, 1, 2, 3], &[10, 11, 12]]), 3);
+    assert!(cons.iter().copied().eq([0, 10, 1, 11, 2, 12]));
+    assert_eq!(prod.push_planar(&[&[4], &[13]]), 1);
+
+    let (mut left, mut right) = ([0; 8], [0; 3]);
+    assert_eq!(cons.pop_planar(&mut [&mut left, &mut right]), 3);
+    assert_eq!((&left[..3], right), (&[0, 1, 2][..], [10, 11, 12]));
+    assert_eq!(cons.pop_planar(&mut [&mut left, &mut right]), 1);
+    assert_eq!((left[0], right[0]), (4, 13));
+    assert!(cons.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn push_slice_cloned() {
+    use alloc::rc::Rc;
+
+    let mut rb = Rb::<Array<Rc<i32>, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let items = [Rc::new(0), Rc::new(1), Rc::new(2)];
+
+    assert_eq!(prod.push_slice_cloned(&items), 3);
+    assert_eq!(cons.try_pop().as_deref(), Some(&0));
+    assert_eq!(prod.push_slice_cloned(&items), 2);
+    assert!(prod.is_full());
+    assert_eq!(Rc::strong_count(&items[0]), 2);
+    assert_eq!(Rc::strong_count(&items[2]), 2);
+
+    assert!(cons.pop_iter().map(|x| *x).eq([1, 2, 0, 1]));
+    assert!(items.iter().all(|x| Rc::strong_count(x) == 1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn push_array() {
+    use alloc::string::String;
+
+    let mut rb = Rb::<Array<String, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(
+        prod.try_push_array([String::from("a"), String::from("b"), String::from("c")]),
+        Ok(())
+    );
+    assert_eq!(cons.try_pop().as_deref(), Some("a"));
+    let arr = prod
+        .try_push_array([String::from("d"), String::from("e"), String::from("f")])
+        .unwrap_err();
+    assert_eq!(arr, ["d", "e", "f"]);
+    assert_eq!(prod.occupied_len(), 2);
+
+    // Wraps around.
+    assert_eq!(prod.try_push_array([String::from("d"), String::from("e")]), Ok(()));
+    assert_eq!(prod.try_push_array([]), Ok(()));
+    assert_eq!(prod.try_push_array([String::from("f")]), Err([String::from("f")]));
+    assert!(cons.pop_iter().eq(["b", "c", "d", "e"]));
+}
+
+#[test]
+fn pop_array() {
+    use alloc::string::{String, ToString};
+
+    let mut rb = Rb::<Array<String, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(["a", "b", "c"].into_iter().map(String::from)), 3);
+    assert_eq!(cons.try_pop_array::<2>(), Some(["a".to_string(), "b".to_string()]));
+    assert_eq!(cons.try_pop_array::<2>(), None);
+    assert_eq!(cons.occupied_len(), 1);
+
+    // Wraps around.
+    assert_eq!(prod.push_iter(["d", "e"].into_iter().map(String::from)), 2);
+    assert_eq!(cons.try_pop_array::<0>(), Some([]));
+    assert_eq!(cons.try_pop_array(), Some(["c".to_string(), "d".to_string(), "e".to_string()]));
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn peek_slice() {
+    let mut rb = Rb::<Array<u8, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut buf = [0; 3];
+    assert_eq!(cons.peek_slice(&mut buf), 3);
+    assert_eq!(buf, [2, 3, 4]);
+    let mut buf = [0; 5];
+    assert_eq!(cons.peek_slice(&mut buf), 4);
+    assert_eq!(buf, [2, 3, 4, 5, 0]);
+    assert_eq!(cons.occupied_len(), 4);
+    assert!(cons.pop_iter().eq(2..6));
+}
+
+#[test]
+fn push_slice_exact() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.try_push_slice_exact(&[0, 1, 2]), Ok(()));
+    assert_eq!(prod.try_push_slice_exact(&[3, 4]), Err(1));
+    assert_eq!(prod.occupied_len(), 3);
+
+    assert_eq!(cons.skip(2), 2);
+    // Wraps around.
+    assert_eq!(prod.try_push_slice_exact(&[3, 4, 5]), Ok(()));
+    assert_eq!(prod.try_push_slice_exact(&[6]), Err(0));
+    assert_eq!(prod.try_push_slice_exact(&[]), Ok(()));
+    assert!(cons.pop_iter().eq(2..6));
+}
+
+#[test]
+fn pop_slice_exact() {
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    let mut buf = [0; 4];
+    assert_eq!(cons.try_pop_slice_exact(&mut buf), Err(3));
+    assert_eq!(cons.occupied_len(), 3);
+    assert_eq!(cons.try_pop_slice_exact(&mut buf[..2]), Ok(()));
+    assert_eq!(buf[..2], [0, 1]);
+
+    // Wraps around.
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+    assert_eq!(cons.try_pop_slice_exact(&mut buf), Ok(()));
+    assert_eq!(buf, [2, 3, 4, 5]);
+    assert_eq!(cons.try_pop_slice_exact(&mut []), Ok(()));
+    assert_eq!(cons.try_pop_slice_exact(&mut buf[..1]), Err(0));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn pop_into_vec() {
+    use alloc::vec::Vec;
+
+    let mut rb = Rb::<Array<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut vec = Vec::from([-1]);
+    assert_eq!(cons.pop_into_vec(&mut vec, 3), 3);
+    assert_eq!(vec, [-1, 2, 3, 4]);
+    assert_eq!(cons.pop_into_vec(&mut vec, 3), 1);
+    assert_eq!(vec, [-1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn pop_collect() {
+    use alloc::{string::String, vec::Vec};
+
+    let mut rb = Rb::<Array<String, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter(["a", "b", "c"].into_iter().map(String::from)), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(["d", "e", "f"].into_iter().map(String::from)), 3);
+
+    assert_eq!(cons.pop_collect::<String>(3), "cde");
+    assert_eq!(cons.occupied_len(), 1);
+    assert_eq!(cons.pop_collect::<Vec<_>>(3), ["f"]);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn pop_slice_uninit() {
+    use crate::utils::uninit_array;
+    use alloc::string::String;
+
+    let mut rb = Rb::<Array<String, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter(["a", "b", "c"].into_iter().map(String::from)), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(["d", "e"].into_iter().map(String::from)), 2);
+
+    // Items are moved into uninitialized memory without `Default` or `Copy`.
+    let mut buf = uninit_array::<String, 4>();
+    assert_eq!(cons.pop_slice_uninit(&mut buf), 3);
+    let items = buf[..3].iter_mut().map(|elem| unsafe { elem.assume_init_read() });
+    assert!(items.eq(["c", "d", "e"]));
+    assert!(cons.is_empty());
+}
@@ -0,0 +1,35 @@
+use crate::{traits::*, HeapRb};
+use alloc::string::{String, ToString};
+
+#[test]
+fn snapshot() {
+    let mut rb = HeapRb::<String>::new(3);
+    rb.push_iter(["a", "b", "c"].map(String::from).into_iter());
+    assert_eq!(rb.skip(2), 2);
+    rb.try_push("d".to_string()).unwrap();
+
+    let mut copy = HeapRb::snapshot(&rb);
+    assert_eq!(copy.capacity(), rb.capacity());
+    assert_eq!((copy.read_index(), copy.write_index()), (rb.read_index(), rb.write_index()));
+    #[cfg(feature = "counters")]
+    assert_eq!((copy.total_pushed(), copy.total_popped()), (Some(4), Some(2)));
+    assert!(copy.iter().eq(rb.iter()));
+
+    copy.try_push("e".to_string()).unwrap();
+    assert!(copy.pop_iter().eq(["c", "d", "e"]));
+    assert!(rb.pop_iter().eq(["c", "d"]));
+}
+
+#[test]
+fn snapshot_split() {
+    let rb = HeapRb::<i32>::new(3);
+    let (mut prod, mut cons) = (&rb).split();
+    prod.push_slice(&[0, 1, 2]);
+    assert_eq!(cons.try_pop(), Some(0));
+
+    let mut copy = HeapRb::snapshot(&cons);
+    assert!(copy.pop_iter().eq([1, 2]));
+    // Original ring buffer stays split and unchanged.
+    assert!(rb.read_is_held() && rb.write_is_held());
+    assert!(cons.pop_iter().eq([1, 2]));
+}
@@ -0,0 +1,66 @@
+use crate::{rb::SplitOnce, traits::*};
+
+#[test]
+fn take_once() {
+    static RB: SplitOnce<i32, 2> = SplitOnce::new();
+
+    let (prod, cons) = RB.take_split();
+    assert!(RB.try_take_split().is_none());
+
+    assert_eq!(prod.try_push(123), Ok(()));
+    assert_eq!(prod.try_push(321), Ok(()));
+    assert_eq!(prod.try_push(444), Err(444));
+
+    assert_eq!(cons.try_pop(), Some(123));
+    assert_eq!(cons.try_pop(), Some(321));
+    assert_eq!(cons.try_pop(), None);
+}
+
+#[test]
+#[should_panic]
+fn take_twice() {
+    static RB: SplitOnce<i32, 2> = SplitOnce::new();
+
+    RB.take_split();
+    RB.take_split();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn concurrent() {
+    use std::thread;
+
+    static RB: SplitOnce<u8, 4> = SplitOnce::new();
+
+    let (prod, cons) = RB.take_split();
+    let pjh = thread::spawn(move || {
+        for i in 0..16 {
+            while prod.try_push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+    let cjh = thread::spawn(move || {
+        for i in 0..16 {
+            loop {
+                if let Some(x) = cons.try_pop() {
+                    assert_eq!(x, i);
+                    break;
+                }
+                thread::yield_now();
+            }
+        }
+    });
+    pjh.join().unwrap();
+    cjh.join().unwrap();
+}
+
+#[cfg(feature = "cache_padding")]
+#[test]
+fn padding() {
+    static RB: SplitOnce<u8, 4> = SplitOnce::new();
+
+    let (prod, cons) = RB.take_split();
+    let (prod, cons) = (prod as *const _ as usize, cons as *const _ as usize);
+    assert!(prod.abs_diff(cons) >= core::mem::align_of::<crossbeam_utils::CachePadded<u8>>());
+}
@@ -0,0 +1,56 @@
+use super::Rb;
+use crate::{storage::Heap, traits::*};
+use core::iter::repeat_n;
+
+#[test]
+fn counter() {
+    let mut rb = Rb::<Heap<()>>::new(4);
+    assert_eq!(rb.capacity().get(), 4);
+
+    assert_eq!(rb.push_iter(repeat_n((), 3)), 3);
+    assert_eq!(rb.occupied_len(), 3);
+    assert_eq!(rb.push_iter(repeat_n((), 3)), 1);
+    assert!(rb.is_full());
+    assert_eq!(rb.try_push(()), Err(()));
+
+    assert_eq!(rb.skip(2), 2);
+    assert_eq!(rb.try_pop(), Some(()));
+    #[cfg(feature = "counters")]
+    assert_eq!((rb.total_pushed(), rb.total_popped()), (Some(4), Some(3)));
+    assert_eq!(rb.pop_iter().count(), 1);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn resize() {
+    let mut rb = Rb::<Heap<()>>::try_new(2).unwrap();
+    rb.push_iter(repeat_n((), 2));
+    rb.grow(5);
+    assert_eq!(rb.capacity().get(), 5);
+    rb.shrink_to_fit();
+    assert_eq!(rb.capacity().get(), 2);
+    assert_eq!(rb.occupied_len(), 2);
+}
+
+#[test]
+fn grow_too_large() {
+    use crate::rb::{CapacityError, Index};
+
+    let mut rb = Rb::<Heap<()>>::new(2);
+    rb.try_push(()).unwrap();
+    assert_eq!(rb.try_grow(usize::MAX), Err(CapacityError::TooLarge));
+    assert_eq!(rb.try_reserve(usize::MAX), Err(CapacityError::TooLarge));
+    assert_eq!(rb.capacity().get(), 2);
+
+    rb.try_grow(<usize as Index>::MAX_CAPACITY).unwrap();
+    assert_eq!(rb.capacity().get(), <usize as Index>::MAX_CAPACITY);
+    assert_eq!(rb.push_iter(core::iter::repeat_n((), 3)), 3);
+    assert_eq!(rb.occupied_len(), 4);
+}
+
+#[test]
+fn from_vec() {
+    let rb = Rb::<Heap<()>>::from(alloc::vec![(); 3]);
+    assert_eq!(rb.capacity().get(), 3);
+    assert!(rb.is_full());
+}
@@ -1,11 +1,22 @@
 use super::{
     observer::{DelegateObserver, Observer},
-    utils::modulus,
+    utils::index_add,
+};
+use crate::utils::{
+    array_assume_init, move_uninit_slice, prefetch, slice_as_uninit_mut, slice_assume_init_mut, slice_assume_init_ref, uninit_array,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    cmp, fmt,
+    iter::Chain,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ops::{ControlFlow, Deref},
+    ptr, slice,
 };
-use crate::utils::{move_uninit_slice, slice_as_uninit_mut, slice_assume_init_mut, slice_assume_init_ref};
-use core::{iter::Chain, marker::PhantomData, mem::MaybeUninit, ptr, slice};
 #[cfg(feature = "std")]
-use std::io::{self, Write};
+use std::io::{self, IoSlice, Write};
 
 /// Consumer part of ring buffer.
 pub trait Consumer: Observer {
@@ -26,7 +37,7 @@ pub trait Consumer: Observer {
     ///
     /// Must not be called concurrently.
     unsafe fn advance_read_index(&self, count: usize) {
-        self.set_read_index((self.read_index() + count) % modulus(self));
+        self.set_read_index(index_add(self, self.read_index(), count));
     }
 
     /// Provides a direct access to the ring buffer occupied memory.
@@ -57,6 +68,35 @@ pub trait Consumer: Observer {
         self.unsafe_slices_mut(self.read_index(), self.write_index())
     }
 
+    /// Borrows the first occupied slice as a single contiguous region.
+    ///
+    /// Items are removed from the ring buffer by [`ReadGrant::release`].
+    fn read_grant(&mut self) -> ReadGrant<'_, Self> {
+        ReadGrant::new(self)
+    }
+
+    /// Borrows the eldest item which is removed only if the guard is committed by [`PopGuard::commit`].
+    ///
+    /// If the guard is dropped without commit (e.g. item processing failed) the item stays in the ring buffer,
+    /// so it will be delivered again.
+    ///
+    /// Returns `None` if the ring buffer is empty.
+    fn peek_guard(&mut self) -> Option<PopGuard<'_, Self>> {
+        let elem = self.first()? as *const Self::Item;
+        Some(PopGuard { cons: self, elem })
+    }
+
+    /// Returns a cursor that reads items without removing them.
+    ///
+    /// Items are removed only by [`Cursor::advance_to_cursor`], so parsing of multi-item frames may be retried on failure.
+    fn cursor(&mut self) -> Cursor<'_, Self> {
+        Cursor {
+            cons: self,
+            pos: 0,
+            mark: 0,
+        }
+    }
+
     /// Returns a pair of slices which contain, in order, the contents of the ring buffer.
     #[inline]
     fn as_slices(&self) -> (&[Self::Item], &[Self::Item]) {
@@ -76,15 +116,38 @@ pub trait Consumer: Observer {
     }
 
     /// Returns a reference to the eldest item in the ring buffer, if exists.
+    ///
+    /// The item stays in the ring buffer, so it can be inspected before deciding whether to pop it.
+    #[doc(alias = "peek")]
     #[inline]
     fn first(&self) -> Option<&Self::Item> {
         self.as_slices().0.first()
     }
     /// Returns a mutable reference to the eldest item in the ring buffer, if exists.
+    #[doc(alias = "peek_mut")]
     #[inline]
     fn first_mut(&mut self) -> Option<&mut Self::Item> {
         self.as_mut_slices().0.first_mut()
     }
+    /// Returns a reference to the item at position `index` counting from the eldest one, if exists.
+    ///
+    /// The item stays in the ring buffer.
+    fn get(&self, index: usize) -> Option<&Self::Item> {
+        let (left, right) = self.as_slices();
+        match left.get(index) {
+            Some(elem) => Some(elem),
+            None => right.get(index - left.len()),
+        }
+    }
+    /// Returns a mutable reference to the item at position `index` counting from the eldest one, if exists.
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+        let (left, right) = self.as_mut_slices();
+        let left_len = left.len();
+        match left.get_mut(index) {
+            Some(elem) => Some(elem),
+            None => right.get_mut(index - left_len),
+        }
+    }
     /// Returns a reference to the most recent item in the ring buffer, if exists.
     ///
     /// *Returned item may not be actually the most recent if there is a concurrent producer activity.*
@@ -112,12 +175,25 @@ pub trait Consumer: Observer {
     ///
     /// Returns `None` if the ring buffer is empty.
     fn try_pop(&mut self) -> Option<Self::Item> {
-        if !self.is_empty() {
-            let elem = unsafe { self.occupied_slices().0.get_unchecked(0).assume_init_read() };
-            unsafe { self.advance_read_index(1) };
-            Some(elem)
-        } else {
-            None
+        // First occupied slice is empty only if the buffer is empty, so indices are loaded only once.
+        let elem = unsafe { self.occupied_slices().0.first()?.assume_init_read() };
+        unsafe { self.advance_read_index(1) };
+        Some(elem)
+    }
+
+    /// Removes the eldest item from the ring buffer distinguishing why it cannot be removed.
+    ///
+    /// Returns [`PopError::Closed`] if the buffer is empty and there is no producer (see [`Self::write_is_held`]),
+    /// so no more items will appear, or [`PopError::Empty`] if pop should be retried later.
+    ///
+    /// *Ring buffer that owns its data has no separate producer, so [`Self::try_pop`] should be used for it instead.*
+    fn try_pop_checked(&mut self) -> Result<Self::Item, PopError> {
+        // Closing is checked before popping to not miss items pushed right before the producer closed.
+        let closed = !self.write_is_held();
+        match self.try_pop() {
+            Some(elem) => Ok(elem),
+            None if closed => Err(PopError::Closed),
+            None => Err(PopError::Empty),
         }
     }
 
@@ -131,6 +207,7 @@ pub trait Consumer: Observer {
             elems.len()
         } else {
             let (left_elems, elems) = elems.split_at_mut(left.len());
+            prefetch(left, right);
             move_uninit_slice(left_elems, left);
             left.len()
                 + if elems.len() < right.len() {
@@ -145,6 +222,24 @@ pub trait Consumer: Observer {
         count
     }
 
+    /// Removes exactly `N` eldest items from the ring buffer or none of them.
+    ///
+    /// Returns `None` if the ring buffer contains less than `N` items.
+    fn try_pop_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        let (left, right) = self.occupied_slices();
+        if left.len() + right.len() < N {
+            return None;
+        }
+        let mut elems = uninit_array::<Self::Item, N>();
+        let mid = cmp::min(left.len(), N);
+        unsafe {
+            move_uninit_slice(elems.get_unchecked_mut(..mid), left.get_unchecked(..mid));
+            move_uninit_slice(elems.get_unchecked_mut(mid..), right.get_unchecked(..(N - mid)));
+            self.advance_read_index(N);
+            Some(array_assume_init(elems))
+        }
+    }
+
     /// Removes items from the ring buffer and writes them into a slice.
     ///
     /// Returns count of items been removed.
@@ -155,6 +250,152 @@ pub trait Consumer: Observer {
         self.pop_slice_uninit(unsafe { slice_as_uninit_mut(elems) })
     }
 
+    /// Removes at most `max` eldest items and appends them to `vec`.
+    ///
+    /// Memory is reserved only once. Returns count of items been removed.
+    #[cfg(feature = "alloc")]
+    fn pop_into_vec(&mut self, vec: &mut Vec<Self::Item>, max: usize) -> usize {
+        let count = cmp::min(max, self.occupied_len());
+        vec.reserve(count);
+        let count = self.pop_slice_uninit(unsafe { vec.spare_capacity_mut().get_unchecked_mut(..count) });
+        unsafe { vec.set_len(vec.len() + count) };
+        count
+    }
+
+    /// Removes at most `max` eldest items and collects them into a collection.
+    ///
+    /// Read index is advanced only once after collecting.
+    fn pop_collect<B: FromIterator<Self::Item>>(&mut self, max: usize) -> B {
+        let (left, right) = self.occupied_slices();
+        let mut guard = AdvanceGuard { cons: self, count: 0 };
+        left.iter()
+            .chain(right)
+            .take(max)
+            .map(|elem| {
+                guard.count += 1;
+                unsafe { elem.assume_init_read() }
+            })
+            .collect()
+    }
+
+    /// Removes eldest items one by one passing them to `f` until it returns [`ControlFlow::Break`] or the ring buffer becomes empty.
+    ///
+    /// Read index is advanced only once. Returns count of items been removed.
+    fn pop_each<F: FnMut(Self::Item) -> ControlFlow<()>>(&mut self, mut f: F) -> usize {
+        let (left, right) = self.occupied_slices();
+        let mut guard = AdvanceGuard { cons: self, count: 0 };
+        for elem in left.iter().chain(right) {
+            guard.count += 1;
+            if f(unsafe { elem.assume_init_read() }).is_break() {
+                break;
+            }
+        }
+        guard.count
+    }
+
+    /// Removes items from the ring buffer filling the whole slice or removes none of them.
+    ///
+    /// If there are not enough items to fill the whole slice returns an `Err` containing the number of occupied items.
+    fn try_pop_slice_exact(&mut self, elems: &mut [Self::Item]) -> Result<(), usize>
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.occupied_slices();
+        let occupied_len = left.len() + right.len();
+        if elems.len() > occupied_len {
+            return Err(occupied_len);
+        }
+        let elems = unsafe { slice_as_uninit_mut(elems) };
+        let mid = cmp::min(left.len(), elems.len());
+        let (left_elems, right_elems) = elems.split_at_mut(mid);
+        unsafe {
+            move_uninit_slice(left_elems, left.get_unchecked(..mid));
+            move_uninit_slice(right_elems, right.get_unchecked(..right_elems.len()));
+            self.advance_read_index(elems.len());
+        }
+        Ok(())
+    }
+
+    /// Copies eldest items into a slice without removing them from the ring buffer.
+    ///
+    /// Returns count of items been copied.
+    fn peek_slice(&self, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.as_slices();
+        if elems.len() <= left.len() {
+            elems.copy_from_slice(&left[..elems.len()]);
+            elems.len()
+        } else {
+            let (left_elems, elems) = elems.split_at_mut(left.len());
+            left_elems.copy_from_slice(left);
+            let count = cmp::min(elems.len(), right.len());
+            elems[..count].copy_from_slice(&right[..count]);
+            left.len() + count
+        }
+    }
+
+    /// Removes items from the ring buffer and fills the whole slice with them without checking whether there are enough items.
+    ///
+    /// # Safety
+    ///
+    /// `elems.len()` must not be greater than [`Self::occupied_len`].
+    unsafe fn pop_slice_unchecked(&mut self, elems: &mut [Self::Item])
+    where
+        Self::Item: Copy,
+    {
+        debug_assert!(elems.len() <= self.occupied_len());
+        let len = elems.len();
+        let (left, right) = self.occupied_slices();
+        let mid = cmp::min(left.len(), len);
+        let (left_elems, right_elems) = slice_as_uninit_mut(elems).split_at_mut_unchecked(mid);
+        if !right_elems.is_empty() {
+            prefetch(left, right);
+        }
+        move_uninit_slice(left_elems, left.get_unchecked(..mid));
+        move_uninit_slice(right_elems, right.get_unchecked(..(len - mid)));
+        self.advance_read_index(len);
+    }
+
+    /// Removes whole frames of `CH` interleaved channels from the ring buffer and writes them into a slice.
+    ///
+    /// Returns count of frames been removed.
+    fn pop_frames<const CH: usize>(&mut self, frames: &mut [[Self::Item; CH]]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        if CH == 0 {
+            return 0;
+        }
+        let count = cmp::min(frames.len(), self.occupied_len() / CH);
+        self.pop_slice(unsafe { frames.get_unchecked_mut(..count) }.as_flattened_mut());
+        count
+    }
+
+    /// Removes interleaved items from the ring buffer and deinterleaves them into planar `channels` (one slice per channel).
+    ///
+    /// Only whole frames are removed, and no more frames than the shortest channel can hold.
+    ///
+    /// Returns count of frames been removed.
+    fn pop_planar(&mut self, channels: &mut [&mut [Self::Item]]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let ch = channels.len();
+        if ch == 0 {
+            return 0;
+        }
+        let frames = channels.iter().map(|c| c.len()).min().unwrap_or(0);
+        let count = cmp::min(frames, self.occupied_len() / ch);
+        let (left, right) = self.as_slices();
+        for (i, item) in left.iter().chain(right.iter()).take(count * ch).enumerate() {
+            unsafe { *channels.get_unchecked_mut(i % ch).get_unchecked_mut(i / ch) = *item };
+        }
+        unsafe { self.advance_read_index(count * ch) };
+        count
+    }
+
     /// Returns an iterator that removes items one by one from the ring buffer.
     fn pop_iter(&mut self) -> PopIter<&mut Self, Self>
     where
@@ -163,6 +404,23 @@ pub trait Consumer: Observer {
         PopIter::new(self)
     }
 
+    /// Returns an iterator that removes eldest items one by one while `f` returns `true` for them.
+    ///
+    /// Items which haven't been taken from the iterator remain in the ring buffer.
+    fn pop_while<F: FnMut(&Self::Item) -> bool>(&mut self, f: F) -> PopWhile<'_, Self, F> {
+        PopWhile { inner: self, f }
+    }
+
+    /// Returns an iterator that removes items by complete chunks of `N` items.
+    ///
+    /// Remaining items which are not enough for a chunk stay in the ring buffer.
+    ///
+    /// *Panics if `N` is zero.*
+    fn pop_chunks<const N: usize>(&mut self) -> PopChunks<'_, Self, N> {
+        assert!(N > 0, "Chunk size must be non-zero");
+        PopChunks { inner: self }
+    }
+
     /// Returns a front-to-back iterator containing references to items in the ring buffer.
     ///
     /// This iterator does not remove items out of the ring buffer.
@@ -179,6 +437,13 @@ pub trait Consumer: Observer {
         left.iter_mut().chain(right.iter_mut())
     }
 
+    /// Returns an adapter which [`Debug`](fmt::Debug) implementation prints capacity, indices and occupied items.
+    ///
+    /// Ring buffers and their parts print only capacity and indices by default because items may not implement `Debug`.
+    fn debug_contents(&self) -> DebugContents<'_, Self> {
+        DebugContents { cons: self }
+    }
+
     /// Removes at most `count` and at least `min(count, Self::len())` items from the buffer and safely drops them.
     ///
     /// If there is no concurring producer activity then exactly `min(count, Self::len())` items are removed.
@@ -201,8 +466,10 @@ pub trait Consumer: Observer {
     fn skip(&mut self, count: usize) -> usize {
         unsafe {
             let (left, right) = self.occupied_slices_mut();
-            for elem in left.iter_mut().chain(right.iter_mut()).take(count) {
-                ptr::drop_in_place(elem.as_mut_ptr());
+            if mem::needs_drop::<Self::Item>() {
+                for elem in left.iter_mut().chain(right.iter_mut()).take(count) {
+                    ptr::drop_in_place(elem.as_mut_ptr());
+                }
             }
             let actual_count = usize::min(count, left.len() + right.len());
             self.advance_read_index(actual_count);
@@ -210,17 +477,117 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Removes and drops eldest items while `f` returns `true` for them.
+    ///
+    /// Read index is advanced only once. Returns the number of removed items.
+    fn skip_while<F: FnMut(&Self::Item) -> bool>(&mut self, mut f: F) -> usize {
+        let (left, right) = self.as_slices();
+        let count = left.iter().chain(right).take_while(|elem| f(elem)).count();
+        self.skip(count)
+    }
+
+    /// Keeps at most `len` most recent items dropping the eldest ones.
+    ///
+    /// Returns the number of removed items.
+    fn truncate_front(&mut self, len: usize) -> usize {
+        self.skip(self.occupied_len().saturating_sub(len))
+    }
+
+    /// Keeps at most `len` eldest items dropping the most recent ones.
+    ///
+    /// Retained items are moved towards the most recent one and then read index is advanced, so this takes `O(len)` time.
+    ///
+    /// Returns the number of removed items.
+    fn truncate_back(&mut self, len: usize) -> usize {
+        let (left, right) = unsafe { self.occupied_slices_mut() };
+        let occupied = left.len() + right.len();
+        if occupied <= len {
+            return 0;
+        }
+        let guard = RetainGuard {
+            left: (left.as_mut_ptr() as *mut Self::Item, left.len()),
+            right: right.as_mut_ptr() as *mut Self::Item,
+            cons: &*self,
+            remaining: len,
+            end: occupied,
+        };
+        // Removed items are skipped by the guard even if dropping of some of them panics.
+        for index in len..occupied {
+            unsafe { ptr::drop_in_place(guard.elem(index)) };
+        }
+        occupied - len
+    }
+
+    /// Retains only the items for which `f` returns `true` preserving their order. Other items are dropped.
+    fn retain<F: FnMut(&Self::Item) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem))
+    }
+
+    /// Same as [`Self::retain`] but passes mutable references to `f`.
+    ///
+    /// Items are visited from the eldest to the most recent one.
+    /// Retained items are moved towards the most recent one and then read index is advanced,
+    /// so it can also be called on a consumer while the producer is active.
+    fn retain_mut<F: FnMut(&mut Self::Item) -> bool>(&mut self, mut f: F) {
+        let (left, right) = unsafe { self.occupied_slices_mut() };
+        let len = left.len() + right.len();
+        let mut guard = BackshiftGuard {
+            base: RetainGuard {
+                left: (left.as_mut_ptr() as *mut Self::Item, left.len()),
+                right: right.as_mut_ptr() as *mut Self::Item,
+                cons: &*self,
+                remaining: 0,
+                end: 0,
+            },
+            processed: 0,
+            deleted: 0,
+            len,
+        };
+        while guard.processed < len {
+            let elem = guard.base.elem(guard.processed);
+            // Item is counted as unprocessed while `f` is called, so it isn't lost if `f` panics.
+            let keep = f(unsafe { &mut *elem });
+            guard.processed += 1;
+            if keep {
+                if guard.deleted > 0 {
+                    unsafe { ptr::copy_nonoverlapping(elem, guard.base.elem(guard.processed - 1 - guard.deleted), 1) };
+                }
+            } else {
+                guard.deleted += 1;
+                unsafe { ptr::drop_in_place(elem) };
+            }
+        }
+    }
+
     /// Removes all items from the buffer and safely drops them.
     ///
+    /// Items are dropped in FIFO order, from the eldest to the most recent one.
+    /// If dropping of some item panics then the rest of items are still dropped and all of them are removed from the ring buffer,
+    /// so there are no leaks or double drops.
+    ///
     /// Returns the number of deleted items.
     fn clear(&mut self) -> usize {
+        /// Drops items of the slice on scope exit, including unwinding.
+        struct DropSlice<T>(*mut [MaybeUninit<T>]);
+        impl<T> Drop for DropSlice<T> {
+            fn drop(&mut self) {
+                unsafe { ptr::drop_in_place(slice_assume_init_mut(&mut *self.0)) };
+            }
+        }
+
         unsafe {
             let (left, right) = self.occupied_slices_mut();
-            for elem in left.iter_mut().chain(right.iter_mut()) {
-                ptr::drop_in_place(elem.as_mut_ptr());
-            }
             let count = left.len() + right.len();
-            self.advance_read_index(count);
+            // Items without drop glue are not iterated, so clearing takes constant time.
+            if mem::needs_drop::<Self::Item>() {
+                let (left, right) = (left as *mut [MaybeUninit<Self::Item>], right as *mut [MaybeUninit<Self::Item>]);
+                // Locals are dropped in reverse order: left items first, then right ones, and only then read index is advanced.
+                let _advance = AdvanceGuard { cons: &*self, count };
+                let _right = DropSlice(right);
+                let _left = DropSlice(left);
+            } else {
+                self.advance_read_index(count);
+            }
             count
         }
     }
@@ -234,7 +601,7 @@ pub trait Consumer: Observer {
     /// + `None`: ring buffer is full or `count` is `0`. In this case `write` isn't called at all.
     /// + `Some(Ok(n))`: `write` succeeded. `n` is number of bytes been written. `n == 0` means that `write` also returned `0`.
     /// + `Some(Err(e))`: `write` is failed and `e` is original error. In this case it is guaranteed that no items was written to the writer.
-    ///    To achieve this we write only one contiguous slice at once. So this call may write less than `occupied_len` items even if the writer is ready to get more.
+    ///   To achieve this we write only one contiguous slice at once. So this call may write less than `occupied_len` items even if the writer is ready to get more.
     fn write_into<S: Write>(&mut self, writer: &mut S, count: Option<usize>) -> Option<io::Result<usize>>
     where
         Self: Consumer<Item = u8>,
@@ -254,6 +621,47 @@ pub trait Consumer: Observer {
         unsafe { self.advance_read_index(write_count) };
         Some(Ok(write_count))
     }
+
+    #[cfg(feature = "std")]
+    /// Removes at most first `count` bytes from the ring buffer and writes them into a [`Write`] instance using [`Write::write_vectored`].
+    /// If `count` is `None` then as much as possible bytes will be written.
+    ///
+    /// Unlike [`Self::write_into`] both occupied slices are passed to the writer at once,
+    /// so the whole ring buffer contents may be written in a single call (e.g. a single `writev` system call).
+    ///
+    /// Returns the same as [`Self::write_into`].
+    fn write_into_vectored<S: Write>(&mut self, writer: &mut S, count: Option<usize>) -> Option<io::Result<usize>>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let (left, right) = self.as_slices();
+        let count = usize::min(count.unwrap_or(usize::MAX), left.len() + right.len());
+        if count == 0 {
+            return None;
+        }
+        let left = &left[..usize::min(count, left.len())];
+        let right = &right[..(count - left.len())];
+
+        let write_count = match writer.write_vectored(&[IoSlice::new(left), IoSlice::new(right)]) {
+            Ok(n) => n,
+            Err(e) => return Some(Err(e)),
+        };
+        assert!(write_count <= count);
+        unsafe { self.advance_read_index(write_count) };
+        Some(Ok(write_count))
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns a pair of [`IoSlice`]s which contain, in order, the contents of the ring buffer.
+    ///
+    /// Can be passed to vectored write (e.g. [`Write::write_vectored`]). Written bytes must be removed by [`Self::skip`] after that.
+    fn occupied_ioslices(&self) -> [IoSlice<'_>; 2]
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let (left, right) = self.as_slices();
+        [IoSlice::new(left), IoSlice::new(right)]
+    }
 }
 
 /// An iterator that removes items from the ring buffer.
@@ -287,6 +695,335 @@ impl<U: AsMut<C> + AsRef<C>, C: Consumer> Iterator for PopIter<U, C> {
     }
 }
 
+/// An iterator that removes items from the ring buffer while predicate holds.
+pub struct PopWhile<'a, C: Consumer + ?Sized, F: FnMut(&C::Item) -> bool> {
+    inner: &'a mut C,
+    f: F,
+}
+
+impl<'a, C: Consumer + ?Sized, F: FnMut(&C::Item) -> bool> Iterator for PopWhile<'a, C, F> {
+    type Item = C::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if (self.f)(self.inner.first()?) {
+            self.inner.try_pop()
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.occupied_len()))
+    }
+}
+
+/// An iterator that removes items from the ring buffer by chunks of `N` items.
+pub struct PopChunks<'a, C: Consumer + ?Sized, const N: usize> {
+    inner: &'a mut C,
+}
+
+impl<'a, C: Consumer + ?Sized, const N: usize> Iterator for PopChunks<'a, C, N> {
+    type Item = [C::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.try_pop_array()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.occupied_len() / N, None)
+    }
+}
+
+/// An owning iterator that removes items from the ring buffer until it is empty and closed.
+///
+/// Blocks by spinning while the ring buffer is empty and producer exists.
+/// Ends when the ring buffer is empty and there is no producer.
+///
+/// Created by `into_drain_iter` of [`SharedRb`](crate::SharedRb) consumers.
+pub struct DrainIter<C: Consumer> {
+    inner: C,
+}
+
+impl<C: Consumer> DrainIter<C> {
+    /// Create an iterator draining `inner`.
+    pub(crate) fn new(inner: C) -> Self {
+        Self { inner }
+    }
+    /// Returns the underlying consumer.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: Consumer> Iterator for DrainIter<C> {
+    type Item = C::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let closed = !self.inner.write_is_held();
+            if let Some(item) = self.inner.try_pop() {
+                return Some(item);
+            }
+            if closed {
+                return None;
+            }
+            core::hint::spin_loop();
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.occupied_len(), None)
+    }
+}
+
+/// Error returned by [`Consumer::try_pop_checked`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PopError {
+    /// The ring buffer is empty.
+    Empty,
+    /// The ring buffer is empty and the producer is dropped.
+    Closed,
+}
+
+impl fmt::Display for PopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PopError::Empty => f.write_str("ring buffer is empty"),
+            PopError::Closed => f.write_str("producer of the ring buffer is closed"),
+        }
+    }
+}
+
+impl core::error::Error for PopError {}
+
+/// Contiguous region of occupied memory borrowed from consumer.
+///
+/// The region stays the same until the grant is released or dropped
+/// so its memory may be passed to DMA or other external reader.
+///
+/// Dropping the grant without release leaves the ring buffer unchanged.
+pub struct ReadGrant<'a, C: Consumer + ?Sized> {
+    cons: &'a mut C,
+    ptr: *const C::Item,
+    len: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> ReadGrant<'a, C> {
+    fn new(cons: &'a mut C) -> Self {
+        let (left, _) = cons.as_slices();
+        let (ptr, len) = (left.as_ptr(), left.len());
+        Self { cons, ptr, len }
+    }
+
+    /// Length of the granted region.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Whether the granted region is empty (ring buffer is empty).
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Granted items.
+    pub fn as_slice(&self) -> &[C::Item] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// Removes first `count` items of the granted region from the ring buffer.
+    ///
+    /// *Panics if `count` is greater than grant length.*
+    pub fn release(self, count: usize) {
+        assert!(count <= self.len);
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr as *mut C::Item, count));
+            self.cons.advance_read_index(count);
+        }
+    }
+}
+
+#[cfg(feature = "embedded-dma")]
+unsafe impl<'a, C: Consumer + ?Sized> embedded_dma::ReadBuffer for ReadGrant<'a, C> {
+    type Word = C::Item;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        (self.ptr, self.len)
+    }
+}
+
+/// Advances read index by count of items been moved out even if the caller panics.
+struct AdvanceGuard<'a, C: Consumer + ?Sized> {
+    cons: &'a C,
+    count: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> Drop for AdvanceGuard<'a, C> {
+    fn drop(&mut self) {
+        unsafe { self.cons.advance_read_index(self.count) };
+    }
+}
+
+/// State of [`Consumer::retain_mut`].
+///
+/// Items before `remaining` are not processed yet, items starting from `end` are retained, items between them are removed.
+/// On drop (including unwinding) not processed items are moved to retained ones and removed items are skipped.
+struct RetainGuard<'a, C: Consumer + ?Sized> {
+    cons: &'a C,
+    left: (*mut C::Item, usize),
+    right: *mut C::Item,
+    remaining: usize,
+    end: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> RetainGuard<'a, C> {
+    fn elem(&self, index: usize) -> *mut C::Item {
+        let (left, left_len) = self.left;
+        if index < left_len {
+            unsafe { left.add(index) }
+        } else {
+            unsafe { self.right.add(index - left_len) }
+        }
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Drop for RetainGuard<'a, C> {
+    fn drop(&mut self) {
+        let removed = self.end - self.remaining;
+        if removed > 0 {
+            for index in (0..self.remaining).rev() {
+                unsafe { ptr::copy_nonoverlapping(self.elem(index), self.elem(index + removed), 1) };
+            }
+            unsafe { self.cons.advance_read_index(removed) };
+        }
+    }
+}
+
+/// Compacts items visited by [`Consumer::retain_mut`] towards the eldest one and then passes them to [`RetainGuard`].
+struct BackshiftGuard<'a, C: Consumer + ?Sized> {
+    base: RetainGuard<'a, C>,
+    processed: usize,
+    deleted: usize,
+    len: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> Drop for BackshiftGuard<'a, C> {
+    fn drop(&mut self) {
+        if self.deleted > 0 {
+            // Unprocessed items are kept, so they are moved to close the gap.
+            for index in self.processed..self.len {
+                unsafe { ptr::copy_nonoverlapping(self.base.elem(index), self.base.elem(index - self.deleted), 1) };
+            }
+        }
+        // Retained items are moved towards the most recent one when `base` is dropped.
+        self.base.remaining = self.len - self.deleted;
+        self.base.end = self.len;
+    }
+}
+
+/// The eldest item of the ring buffer borrowed from consumer until it is committed.
+///
+/// Dropping the guard without commit leaves the ring buffer unchanged.
+pub struct PopGuard<'a, C: Consumer + ?Sized> {
+    cons: &'a mut C,
+    elem: *const C::Item,
+}
+
+impl<'a, C: Consumer + ?Sized> PopGuard<'a, C> {
+    /// Removes the item from the ring buffer and returns it.
+    pub fn commit(self) -> C::Item {
+        unsafe {
+            let elem = self.elem.read();
+            self.cons.advance_read_index(1);
+            elem
+        }
+    }
+}
+
+impl<'a, C: Consumer + ?Sized> Deref for PopGuard<'a, C> {
+    type Target = C::Item;
+
+    fn deref(&self) -> &C::Item {
+        unsafe { &*self.elem }
+    }
+}
+
+/// Debug representation of the ring buffer including its items.
+///
+/// Created by [`Consumer::debug_contents`].
+pub struct DebugContents<'a, C: Consumer + ?Sized> {
+    cons: &'a C,
+}
+
+impl<'a, C: Consumer + ?Sized> fmt::Debug for DebugContents<'a, C>
+where
+    C::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugContents")
+            .field("capacity", &self.cons.capacity())
+            .field("read_index", &self.cons.read_index())
+            .field("write_index", &self.cons.write_index())
+            .field("items", &DebugItems(self.cons))
+            .finish()
+    }
+}
+
+struct DebugItems<'a, C: Consumer + ?Sized>(&'a C);
+
+impl<'a, C: Consumer + ?Sized> fmt::Debug for DebugItems<'a, C>
+where
+    C::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+/// Rewindable read position within the occupied items of the ring buffer.
+///
+/// Dropping the cursor without advance leaves the ring buffer unchanged.
+pub struct Cursor<'a, C: Consumer + ?Sized> {
+    cons: &'a mut C,
+    pos: usize,
+    mark: usize,
+}
+
+impl<'a, C: Consumer + ?Sized> Cursor<'a, C> {
+    /// Number of items been read since the eldest one.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+    /// Number of items remaining after the cursor.
+    pub fn remaining(&self) -> usize {
+        self.cons.occupied_len() - self.pos
+    }
+
+    /// Reads the item at the cursor and moves the cursor forward.
+    ///
+    /// Returns `None` if there are no more items.
+    pub fn read(&mut self) -> Option<&C::Item> {
+        let elem = self.cons.get(self.pos)?;
+        self.pos += 1;
+        Some(elem)
+    }
+
+    /// Remembers current position to return to it by [`Self::reset`].
+    pub fn mark(&mut self) {
+        self.mark = self.pos;
+    }
+    /// Moves the cursor back to the last mark or to the eldest item if there were no marks.
+    pub fn reset(&mut self) {
+        self.pos = self.mark;
+    }
+
+    /// Removes all items before the cursor from the ring buffer.
+    pub fn advance_to_cursor(self) {
+        self.cons.skip(self.pos);
+    }
+}
+
 /// Iterator over ring buffer contents.
 ///
 /// *Please do not rely on actual type, it may change in future.*
@@ -343,6 +1080,43 @@ where
         self.base_mut().try_pop()
     }
 
+    #[inline]
+    fn try_pop_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        self.base_mut().try_pop_array()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn pop_into_vec(&mut self, vec: &mut Vec<Self::Item>, max: usize) -> usize {
+        self.base_mut().pop_into_vec(vec, max)
+    }
+
+    #[inline]
+    fn pop_collect<B: FromIterator<Self::Item>>(&mut self, max: usize) -> B {
+        self.base_mut().pop_collect(max)
+    }
+
+    #[inline]
+    fn pop_each<F: FnMut(Self::Item) -> ControlFlow<()>>(&mut self, f: F) -> usize {
+        self.base_mut().pop_each(f)
+    }
+
+    #[inline]
+    fn try_pop_slice_exact(&mut self, elems: &mut [Self::Item]) -> Result<(), usize>
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().try_pop_slice_exact(elems)
+    }
+
+    #[inline]
+    fn peek_slice(&self, elems: &mut [Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        self.base().peek_slice(elems)
+    }
+
     #[inline]
     fn pop_slice(&mut self, elems: &mut [Self::Item]) -> usize
     where
@@ -351,6 +1125,14 @@ where
         self.base_mut().pop_slice(elems)
     }
 
+    #[inline]
+    unsafe fn pop_slice_unchecked(&mut self, elems: &mut [Self::Item])
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().pop_slice_unchecked(elems)
+    }
+
     #[inline]
     fn iter(&self) -> Iter<'_, Self> {
         self.base().iter()
@@ -366,6 +1148,31 @@ where
         self.base_mut().skip(count)
     }
 
+    #[inline]
+    fn skip_while<F: FnMut(&Self::Item) -> bool>(&mut self, f: F) -> usize {
+        self.base_mut().skip_while(f)
+    }
+
+    #[inline]
+    fn truncate_front(&mut self, len: usize) -> usize {
+        self.base_mut().truncate_front(len)
+    }
+
+    #[inline]
+    fn truncate_back(&mut self, len: usize) -> usize {
+        self.base_mut().truncate_back(len)
+    }
+
+    #[inline]
+    fn retain<F: FnMut(&Self::Item) -> bool>(&mut self, f: F) {
+        self.base_mut().retain(f)
+    }
+
+    #[inline]
+    fn retain_mut<F: FnMut(&mut Self::Item) -> bool>(&mut self, f: F) {
+        self.base_mut().retain_mut(f)
+    }
+
     #[inline]
     fn clear(&mut self) -> usize {
         self.base_mut().clear()
@@ -395,7 +1202,83 @@ macro_rules! impl_consumer_traits {
                     Ok(n)
                 }
             }
+
+            /// Moves bytes directly into uninitialized memory, so the destination doesn't need to be zeroed.
+            #[cfg(feature = "read_buf")]
+            fn read_buf(&mut self, mut cursor: std::io::BorrowedCursor<'_>) -> std::io::Result<()> {
+                let n = self.pop_slice_uninit(unsafe { cursor.as_mut() });
+                if n == 0 {
+                    Err(std::io::ErrorKind::WouldBlock.into())
+                } else {
+                    unsafe { cursor.advance(n) };
+                    Ok(())
+                }
+            }
+        }
+
+        /// Returns [`WouldBlock`](std::io::ErrorKind::WouldBlock) if the ring buffer is empty and producer exists.
+        ///
+        /// Returns empty slice (end of file) if the ring buffer is empty and there is no producer.
+        ///
+        /// Provided [`read_until`](std::io::BufRead::read_until) and [`read_line`](std::io::BufRead::read_line)
+        /// search for delimiter in both occupied slices one by one using accelerated `memchr` of `std`.
+        #[cfg(feature = "std")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? std::io::BufRead for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Consumer<Item = u8>,
+        {
+            fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+                if self.is_empty() && self.write_is_held() {
+                    return Err(std::io::ErrorKind::WouldBlock.into());
+                }
+                Ok(self.as_slices().0)
+            }
+            fn consume(&mut self, amt: usize) {
+                assert!(amt <= self.occupied_len());
+                unsafe { self.advance_read_index(amt) };
+            }
+        }
+
+        #[cfg(feature = "embedded-io")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? embedded_io::ReadReady for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Consumer<Item = u8>,
+        {
+            fn read_ready(&mut self) -> Result<bool, Self::Error> {
+                Ok(!self.is_empty())
+            }
         }
     };
 }
 pub(crate) use impl_consumer_traits;
+
+/// Implements blocking I/O traits which wait by spinning until the opposite end makes progress.
+///
+/// Not applicable to frozen wrappers because they don't see the opposite end progress without synchronization.
+macro_rules! impl_blocking_consumer_traits {
+    ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
+        /// Blocks by spinning while the ring buffer is empty and producer exists.
+        ///
+        /// Returns `Ok(0)` (end of file) if the ring buffer is empty and there is no producer.
+        #[cfg(feature = "embedded-io")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? embedded_io::Read for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Consumer<Item = u8>,
+        {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+                if buf.is_empty() {
+                    return Ok(0);
+                }
+                loop {
+                    let closed = !self.write_is_held();
+                    let n = self.pop_slice(buf);
+                    if n != 0 || closed {
+                        return Ok(n);
+                    }
+                    core::hint::spin_loop();
+                }
+            }
+        }
+    };
+}
+pub(crate) use impl_blocking_consumer_traits;
@@ -1,5 +1,8 @@
-use super::{utils::modulus, Based};
-use core::{mem::MaybeUninit, num::NonZeroUsize};
+use super::{
+    utils::{index_add, index_sub},
+    Based,
+};
+use core::{cmp, mem::MaybeUninit, num::NonZeroUsize};
 
 /// Ring buffer observer.
 ///
@@ -21,6 +24,31 @@ pub trait Observer {
     /// Index value is in range `0..(2 * capacity)`.
     fn write_index(&self) -> usize;
 
+    /// Total number of items ever pushed into the ring buffer.
+    ///
+    /// Unlike [`Self::write_index`] it doesn't wrap around.
+    /// Items that were in the ring buffer when it was created are counted as pushed.
+    ///
+    /// Returns `None` if the ring buffer doesn't track lifetime counters.
+    /// Built-in ring buffers track them only when the `counters` feature is enabled.
+    ///
+    /// *Actual number may be greater than returned value due to concurring producer activity.*
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        None
+    }
+    /// Total number of items ever popped (or skipped) from the ring buffer.
+    ///
+    /// Unlike [`Self::read_index`] it doesn't wrap around.
+    ///
+    /// Returns `None` if the ring buffer doesn't track lifetime counters (see [`Self::total_pushed`] for details).
+    ///
+    /// *Actual number may be greater than returned value due to concurring consumer activity.*
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        None
+    }
+
     /// Get slice between `start` and `end` indices.
     ///
     /// # Safety
@@ -33,6 +61,7 @@ pub trait Observer {
     /// # Safety
     ///
     /// There must not exist overlapping slices at the same time.
+    #[allow(clippy::mut_from_ref)]
     unsafe fn unsafe_slices_mut(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]);
 
     /// Whether read end is held by consumer.
@@ -40,20 +69,62 @@ pub trait Observer {
     /// Whether write end is held by producer.
     fn write_is_held(&self) -> bool;
 
+    /// Read and write indices loaded without synchronization.
+    ///
+    /// Used by [`Self::occupied_len_relaxed`] and [`Self::vacant_len_relaxed`].
+    /// Multi-threaded ring buffers load indices with [`Relaxed`](core::sync::atomic::Ordering::Relaxed) ordering.
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        (self.read_index(), self.write_index())
+    }
+
+    /// Approximate number of items stored in the buffer.
+    ///
+    /// Unlike [`Self::occupied_len`] it doesn't synchronize with producer and consumer,
+    /// so it can be used to monitor the ring buffer from another thread (e.g. to collect metrics) without slowing them down.
+    ///
+    /// *Returned value is never greater than capacity but must not be used to access items.*
+    fn occupied_len_relaxed(&self) -> usize {
+        let (read, write) = self.indices_relaxed();
+        cmp::min(index_sub(self, write, read), self.capacity().get())
+    }
+
+    /// Approximate number of remaining free places in the buffer.
+    ///
+    /// See [`Self::occupied_len_relaxed`] for details.
+    fn vacant_len_relaxed(&self) -> usize {
+        self.capacity().get() - self.occupied_len_relaxed()
+    }
+
+    /// Numbers of occupied and vacant places in the ring buffer obtained from a single load of both indices.
+    ///
+    /// Use it instead of calling several of [`Self::occupied_len`], [`Self::vacant_len`], [`Self::is_empty`] and [`Self::is_full`]
+    /// to avoid redundant loads of indices.
+    ///
+    /// *The values may become irrelevant at any time because of concurring activity of producer and consumer.*
+    fn occupancy(&self) -> Occupancy {
+        let read = self.read_index();
+        let occupied = index_sub(self, self.write_index(), read);
+        Occupancy {
+            occupied,
+            vacant: self.capacity().get() - occupied,
+        }
+    }
+
     /// The number of items stored in the buffer.
     ///
     /// *Actual number may be greater or less than returned value due to concurring activity of producer or consumer respectively.*
     fn occupied_len(&self) -> usize {
-        let modulus = modulus(self);
-        (modulus.get() + self.write_index() - self.read_index()) % modulus
+        let write = self.write_index();
+        index_sub(self, write, self.read_index())
     }
 
     /// The number of remaining free places in the buffer.
     ///
     /// *Actual number may be greater or less than returned value due to concurring activity of consumer or producer respectively.*
     fn vacant_len(&self) -> usize {
-        let modulus = modulus(self);
-        (self.capacity().get() + self.read_index() - self.write_index()) % modulus
+        let end = index_add(self, self.read_index(), self.capacity().get());
+        index_sub(self, end, self.write_index())
     }
 
     /// Checks if the ring buffer is empty.
@@ -73,6 +144,28 @@ pub trait Observer {
     }
 }
 
+/// Snapshot of ring buffer occupancy returned by [`Observer::occupancy`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Occupancy {
+    /// The number of items stored in the buffer.
+    pub occupied: usize,
+    /// The number of remaining free places in the buffer.
+    pub vacant: usize,
+}
+
+impl Occupancy {
+    /// Whether there are no items stored in the buffer.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.occupied == 0
+    }
+    /// Whether there are no free places in the buffer.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.vacant == 0
+    }
+}
+
 /// Trait used for delegating observer methods.
 pub trait DelegateObserver: Based
 where
@@ -100,6 +193,15 @@ where
         self.base().write_index()
     }
 
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        self.base().total_pushed()
+    }
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        self.base().total_popped()
+    }
+
     #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.base().unsafe_slices(start, end)
@@ -118,6 +220,16 @@ where
         self.base().write_is_held()
     }
 
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        self.base().indices_relaxed()
+    }
+
+    #[inline]
+    fn occupancy(&self) -> Occupancy {
+        self.base().occupancy()
+    }
+
     #[inline]
     fn occupied_len(&self) -> usize {
         self.base().occupied_len()
@@ -138,3 +250,48 @@ where
         self.base().is_full()
     }
 }
+
+macro_rules! impl_observer_traits {
+    ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
+        #[cfg(feature = "embedded-io")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? embedded_io::ErrorType for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Observer<Item = u8>,
+        {
+            type Error = embedded_io::ErrorKind;
+        }
+
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? core::fmt::Debug for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Observer,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($type))
+                    .field("capacity", &self.capacity())
+                    .field("read_index", &self.read_index())
+                    .field("write_index", &self.write_index())
+                    .field("occupied_len", &self.occupied_len())
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? defmt::Format for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Observer,
+        {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(
+                    f,
+                    "{=str} {{ capacity: {=usize}, read_index: {=usize}, write_index: {=usize}, occupied_len: {=usize} }}",
+                    stringify!($type),
+                    self.capacity().get(),
+                    self.read_index(),
+                    self.write_index(),
+                    self.occupied_len(),
+                )
+            }
+        }
+    };
+}
+pub(crate) use impl_observer_traits;
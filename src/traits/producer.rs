@@ -1,16 +1,15 @@
 use super::{
     observer::{DelegateObserver, Observer},
-    utils::modulus,
+    utils::index_add,
 };
+use crate::utils::{array_to_uninit, move_uninit_slice, prefetch, slice_assume_init_mut, write_slice};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::{cmp, fmt, iter::Peekable, mem::MaybeUninit, ptr, slice};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::{AtomicUsize, Ordering};
 #[cfg(feature = "std")]
-use crate::utils::slice_assume_init_mut;
-use crate::utils::write_slice;
-use core::mem::MaybeUninit;
-#[cfg(feature = "std")]
-use std::{
-    cmp,
-    io::{self, Read},
-};
+use std::io::{self, IoSliceMut, Read};
 
 /// Producer part of ring buffer.
 pub trait Producer: Observer {
@@ -31,14 +30,15 @@ pub trait Producer: Observer {
     ///
     /// Must not be called concurrently.
     unsafe fn advance_write_index(&self, count: usize) {
-        self.set_write_index((self.write_index() + count) % modulus(self));
+        self.set_write_index(index_add(self, self.write_index(), count));
     }
 
     /// Provides a direct access to the ring buffer vacant memory.
     ///
     /// Returns a pair of slices of uninitialized memory, the second one may be empty.
     fn vacant_slices(&self) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
-        unsafe { self.unsafe_slices(self.write_index(), self.read_index() + self.capacity().get()) }
+        let end = index_add(self, self.read_index(), self.capacity().get());
+        unsafe { self.unsafe_slices(self.write_index(), end) }
     }
 
     /// Mutable version of [`Self::vacant_slices`].
@@ -51,22 +51,102 @@ pub trait Producer: Observer {
     ///
     /// *Vacant slices must not be used to store any data because their contents aren't synchronized properly.*
     fn vacant_slices_mut(&mut self) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
-        unsafe { self.unsafe_slices_mut(self.write_index(), self.read_index() + self.capacity().get()) }
+        let end = index_add(self, self.read_index(), self.capacity().get());
+        unsafe { self.unsafe_slices_mut(self.write_index(), end) }
+    }
+
+    /// Borrows the first vacant slice as a single contiguous region.
+    ///
+    /// Items put into the grant are committed to the ring buffer by [`WriteGrant::commit`].
+    fn write_grant(&mut self) -> WriteGrant<'_, Self> {
+        WriteGrant::new(self)
+    }
+
+    /// Borrows a contiguous region of exactly `len` vacant items.
+    ///
+    /// Returns `None` if the first vacant slice is shorter than `len` (even if there is enough vacant space in total).
+    /// Dropping the grant without commit leaves the ring buffer unchanged.
+    fn write_grant_exact(&mut self, len: usize) -> Option<WriteGrant<'_, Self>> {
+        let mut grant = WriteGrant::new(self);
+        if grant.len < len {
+            return None;
+        }
+        grant.len = len;
+        Some(grant)
+    }
+
+    /// Reserves exactly `len` vacant slots which may be written in any order.
+    ///
+    /// The slots are appended to the ring buffer by [`SlotWriter::publish`] only when all of them are written.
+    /// Returns `None` if there is not enough vacant space.
+    fn reserve(&mut self, len: usize) -> Option<SlotWriter<'_, Self>> {
+        let mut writer = SlotWriter::new(self);
+        if writer.vacant < len {
+            return None;
+        }
+        writer.len = len;
+        Some(writer)
     }
 
     /// Appends an item to the ring buffer.
     ///
     /// If buffer is full returns an `Err` containing the item that hasn't been appended.
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
-        if !self.is_full() {
-            unsafe {
-                self.vacant_slices_mut().0.get_unchecked_mut(0).write(elem);
-                self.advance_write_index(1)
-            };
-            Ok(())
-        } else {
-            Err(elem)
+        // First vacant slice is empty only if the buffer is full, so indices are loaded only once.
+        match self.vacant_slices_mut().0.first_mut() {
+            Some(place) => {
+                place.write(elem);
+                unsafe { self.advance_write_index(1) };
+                Ok(())
+            }
+            None => Err(elem),
+        }
+    }
+
+    /// Appends an item returned by `f` to the ring buffer.
+    ///
+    /// `f` is called only if there is a vacant place for the item, otherwise it is returned back in `Err`.
+    fn try_push_with<F: FnOnce() -> Self::Item>(&mut self, f: F) -> Result<(), F> {
+        match self.vacant_slices_mut().0.first_mut() {
+            Some(place) => {
+                place.write(f());
+                unsafe { self.advance_write_index(1) };
+                Ok(())
+            }
+            None => Err(f),
+        }
+    }
+
+    /// Initializes an item directly in the vacant place of the ring buffer by calling `f` and appends it.
+    ///
+    /// It allows to construct large items in place without moving them.
+    /// `f` is called only if there is a vacant place for the item, otherwise it is returned back in `Err`.
+    ///
+    /// # Safety
+    ///
+    /// `f` must initialize the place passed to it.
+    unsafe fn try_push_init<F: FnOnce(&mut MaybeUninit<Self::Item>)>(&mut self, f: F) -> Result<(), F> {
+        match self.vacant_slices_mut().0.first_mut() {
+            Some(place) => {
+                f(place);
+                self.advance_write_index(1);
+                Ok(())
+            }
+            None => Err(f),
+        }
+    }
+
+    /// Appends an item to the ring buffer distinguishing why it cannot be appended.
+    ///
+    /// Returns [`PushError::Closed`] if there is no consumer (see [`Self::read_is_held`]), so the item will never be taken,
+    /// or [`PushError::Full`] if the buffer is full and push should be retried later.
+    ///
+    /// *Ring buffer that owns its data has no separate consumer, so [`Self::try_push`] should be used for it instead.*
+    fn try_push_checked(&mut self, elem: Self::Item) -> Result<(), PushError<Self::Item>> {
+        if !self.read_is_held() {
+            return Err(PushError::Closed(elem));
         }
+        self.try_push(elem).map_err(PushError::Full)
     }
 
     /// Appends items from an iterator to the ring buffer.
@@ -74,6 +154,9 @@ pub trait Producer: Observer {
     ///
     /// Returns count of items been appended to the ring buffer.
     ///
+    /// Pass the iterator by mutable reference to push the rest of items later,
+    /// or use [`Self::try_extend`] that returns the remaining items.
+    ///
     /// *Inserted items are committed to the ring buffer all at once in the end,*
     /// *e.g. when buffer is full or iterator has ended.*
     fn push_iter<I: Iterator<Item = Self::Item>>(&mut self, mut iter: I) -> usize {
@@ -90,6 +173,19 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Appends items from an iterator to the ring buffer until it is full.
+    ///
+    /// Returns `Ok` if all items have been appended,
+    /// otherwise returns an `Err` containing the iterator over the rest of items.
+    fn try_extend<I: IntoIterator<Item = Self::Item>>(&mut self, iter: I) -> Result<(), Peekable<I::IntoIter>> {
+        let mut iter = iter.into_iter().peekable();
+        self.push_iter(&mut iter);
+        match iter.peek() {
+            None => Ok(()),
+            Some(_) => Err(iter),
+        }
+    }
+
     /// Appends items from slice to the ring buffer.
     ///
     /// Returns count of items been appended to the ring buffer.
@@ -99,17 +195,18 @@ pub trait Producer: Observer {
     {
         let (left, right) = self.vacant_slices_mut();
         let count = if elems.len() < left.len() {
-            write_slice(&mut left[..elems.len()], elems);
+            write_slice(unsafe { left.get_unchecked_mut(..elems.len()) }, elems);
             elems.len()
         } else {
             let (left_elems, elems) = elems.split_at(left.len());
+            prefetch(left, right);
             write_slice(left, left_elems);
             left.len()
                 + if elems.len() < right.len() {
-                    write_slice(&mut right[..elems.len()], elems);
+                    write_slice(unsafe { right.get_unchecked_mut(..elems.len()) }, elems);
                     elems.len()
                 } else {
-                    write_slice(right, &elems[..right.len()]);
+                    write_slice(right, unsafe { elems.get_unchecked(..right.len()) });
                     right.len()
                 }
         };
@@ -117,6 +214,117 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Appends all items from array to the ring buffer or none of them.
+    ///
+    /// If there is not enough vacant space for the whole array returns an `Err` containing it.
+    fn try_push_array<const N: usize>(&mut self, elems: [Self::Item; N]) -> Result<(), [Self::Item; N]> {
+        let (left, right) = self.vacant_slices_mut();
+        if left.len() + right.len() < N {
+            return Err(elems);
+        }
+        let elems = array_to_uninit(elems);
+        let mid = cmp::min(left.len(), N);
+        unsafe {
+            move_uninit_slice(left.get_unchecked_mut(..mid), elems.get_unchecked(..mid));
+            move_uninit_slice(right.get_unchecked_mut(..(N - mid)), elems.get_unchecked(mid..));
+            self.advance_write_index(N);
+        }
+        Ok(())
+    }
+
+    /// Appends clones of items from slice to the ring buffer.
+    ///
+    /// Unlike [`Self::push_slice`] it doesn't require items to be [`Copy`], but write index is still updated only once.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn push_slice_cloned(&mut self, elems: &[Self::Item]) -> usize
+    where
+        Self::Item: Clone,
+    {
+        self.push_iter(elems.iter().cloned())
+    }
+
+    /// Appends all items from slice to the ring buffer or none of them.
+    ///
+    /// If there is not enough vacant space for the whole slice returns an `Err` containing the number of vacant places.
+    fn try_push_slice_exact(&mut self, elems: &[Self::Item]) -> Result<(), usize>
+    where
+        Self::Item: Copy,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        let vacant_len = left.len() + right.len();
+        if elems.len() > vacant_len {
+            return Err(vacant_len);
+        }
+        let mid = cmp::min(left.len(), elems.len());
+        let (left_elems, right_elems) = elems.split_at(mid);
+        unsafe {
+            write_slice(left.get_unchecked_mut(..mid), left_elems);
+            write_slice(right.get_unchecked_mut(..right_elems.len()), right_elems);
+            self.advance_write_index(elems.len());
+        }
+        Ok(())
+    }
+
+    /// Appends all items from slice to the ring buffer without checking whether there is enough vacant space.
+    ///
+    /// # Safety
+    ///
+    /// `elems.len()` must not be greater than [`Self::vacant_len`].
+    unsafe fn push_slice_unchecked(&mut self, elems: &[Self::Item])
+    where
+        Self::Item: Copy,
+    {
+        debug_assert!(elems.len() <= self.vacant_len());
+        let (left, right) = self.vacant_slices_mut();
+        let mid = cmp::min(left.len(), elems.len());
+        let (left_elems, right_elems) = elems.split_at_unchecked(mid);
+        if !right_elems.is_empty() {
+            prefetch(left, right);
+        }
+        write_slice(left.get_unchecked_mut(..mid), left_elems);
+        write_slice(right.get_unchecked_mut(..right_elems.len()), right_elems);
+        self.advance_write_index(elems.len());
+    }
+
+    /// Appends whole frames of `CH` interleaved channels to the ring buffer.
+    ///
+    /// Returns count of frames been appended to the ring buffer.
+    fn push_frames<const CH: usize>(&mut self, frames: &[[Self::Item; CH]]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        if CH == 0 {
+            return 0;
+        }
+        let count = cmp::min(frames.len(), self.vacant_len() / CH);
+        self.push_slice(unsafe { frames.get_unchecked(..count) }.as_flattened());
+        count
+    }
+
+    /// Interleaves items from planar `channels` (one slice per channel) and appends them to the ring buffer.
+    ///
+    /// Only whole frames are appended, and no more frames than the shortest channel contains.
+    ///
+    /// Returns count of frames been appended to the ring buffer.
+    fn push_planar(&mut self, channels: &[&[Self::Item]]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        let ch = channels.len();
+        if ch == 0 {
+            return 0;
+        }
+        let frames = channels.iter().map(|c| c.len()).min().unwrap_or(0);
+        let count = cmp::min(frames, self.vacant_len() / ch);
+        let (left, right) = self.vacant_slices_mut();
+        for (i, place) in left.iter_mut().chain(right.iter_mut()).take(count * ch).enumerate() {
+            place.write(unsafe { *channels.get_unchecked(i % ch).get_unchecked(i / ch) });
+        }
+        unsafe { self.advance_write_index(count * ch) };
+        count
+    }
+
     #[cfg(feature = "std")]
     /// Reads at most `count` bytes from `Read` instance and appends them to the ring buffer.
     /// If `count` is `None` then as much as possible bytes will be read.
@@ -150,6 +358,248 @@ pub trait Producer: Observer {
         unsafe { self.advance_write_index(read_count) };
         Some(Ok(read_count))
     }
+
+    #[cfg(feature = "std")]
+    /// Returns a pair of [`IoSliceMut`]s of the ring buffer vacant memory.
+    ///
+    /// Can be passed to vectored read (e.g. [`Read::read_vectored`]).
+    /// Vacant memory is filled with zeros before that because there is no way to read into uninitialized buffer in stable Rust yet.
+    ///
+    /// *This method must be followed by [`Self::advance_write_index`] call with the number of bytes being read as argument.*
+    fn vacant_ioslices_mut(&mut self) -> [IoSliceMut<'_>; 2]
+    where
+        Self: Producer<Item = u8>,
+    {
+        let (left, right) = self.vacant_slices_mut();
+        left.fill(MaybeUninit::new(0));
+        right.fill(MaybeUninit::new(0));
+        unsafe {
+            [
+                IoSliceMut::new(slice_assume_init_mut(left)),
+                IoSliceMut::new(slice_assume_init_mut(right)),
+            ]
+        }
+    }
+}
+
+/// Error returned by [`Producer::try_push_checked`] containing the item that hasn't been appended.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PushError<T> {
+    /// The ring buffer is full.
+    Full(T),
+    /// The consumer is dropped.
+    Closed(T),
+}
+
+impl<T> PushError<T> {
+    /// Returns the item that hasn't been appended.
+    pub fn into_inner(self) -> T {
+        match self {
+            PushError::Full(elem) | PushError::Closed(elem) => elem,
+        }
+    }
+}
+
+impl<T> fmt::Debug for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::Full(_) => f.write_str("Full(..)"),
+            PushError::Closed(_) => f.write_str("Closed(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::Full(_) => f.write_str("ring buffer is full"),
+            PushError::Closed(_) => f.write_str("consumer of the ring buffer is closed"),
+        }
+    }
+}
+
+impl<T> core::error::Error for PushError<T> {}
+
+/// Contiguous region of vacant memory borrowed from producer.
+///
+/// The region stays the same until the grant is committed or dropped
+/// so its memory may be passed to DMA or other external writer.
+///
+/// Dropping the grant without commit leaves the ring buffer unchanged.
+pub struct WriteGrant<'a, P: Producer + ?Sized> {
+    prod: &'a mut P,
+    ptr: *mut MaybeUninit<P::Item>,
+    len: usize,
+}
+
+impl<'a, P: Producer + ?Sized> WriteGrant<'a, P> {
+    fn new(prod: &'a mut P) -> Self {
+        let (left, _) = prod.vacant_slices_mut();
+        let (ptr, len) = (left.as_mut_ptr(), left.len());
+        Self { prod, ptr, len }
+    }
+
+    /// Length of the granted region.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Whether the granted region is empty (ring buffer is full).
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Granted memory.
+    pub fn as_mut_slice(&mut self) -> &mut [MaybeUninit<P::Item>] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    /// Commits first `count` items of the granted region to the ring buffer.
+    ///
+    /// # Safety
+    ///
+    /// First `count` items of the granted region must be initialized.
+    ///
+    /// *Panics if `count` is greater than grant length.*
+    pub unsafe fn commit(self, count: usize) {
+        assert!(count <= self.len);
+        self.prod.advance_write_index(count);
+    }
+}
+
+#[cfg(feature = "embedded-dma")]
+unsafe impl<'a, P: Producer + ?Sized> embedded_dma::WriteBuffer for WriteGrant<'a, P>
+where
+    P::Item: embedded_dma::Word,
+{
+    type Word = P::Item;
+
+    unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+        (self.ptr as *mut P::Item, self.len)
+    }
+}
+
+/// Reserved vacant slots of the ring buffer that are written independently of each other.
+///
+/// Slots may be distributed between threads (e.g. [`std::thread::scope`]) and written in parallel.
+/// Once all slots are written the whole region is appended to the ring buffer at once.
+///
+/// # Leaking
+///
+/// Written slots are not tracked individually, only their number is counted.
+/// So if the writer is dropped without publishing then the written items are dropped only if all slots were written,
+/// otherwise they are leaked (but the ring buffer itself stays valid and the slots are reused later).
+pub struct SlotWriter<'a, P: Producer + ?Sized> {
+    prod: &'a mut P,
+    left: (*mut MaybeUninit<P::Item>, usize),
+    right: *mut MaybeUninit<P::Item>,
+    vacant: usize,
+    len: usize,
+    taken: bool,
+    done: AtomicUsize,
+}
+
+impl<'a, P: Producer + ?Sized> SlotWriter<'a, P> {
+    fn new(prod: &'a mut P) -> Self {
+        let (left, right) = prod.vacant_slices_mut();
+        let (left, right, vacant) = ((left.as_mut_ptr(), left.len()), right.as_mut_ptr(), left.len() + right.len());
+        Self {
+            prod,
+            left,
+            right,
+            vacant,
+            len: vacant,
+            taken: false,
+            done: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of reserved slots.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Whether there are no reserved slots.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Number of slots already written.
+    pub fn completed(&self) -> usize {
+        self.done.load(Ordering::Acquire)
+    }
+    /// Whether all reserved slots are written, so the writer can be published.
+    pub fn is_complete(&self) -> bool {
+        self.completed() == self.len
+    }
+
+    /// Takes all reserved slots in order.
+    ///
+    /// Slots which are dropped without being written remain incomplete, so the writer cannot be published anymore.
+    ///
+    /// *Panics if slots have already been taken.*
+    pub fn slots(&mut self) -> impl Iterator<Item = Slot<'_, P::Item>> {
+        assert!(!self.taken, "Slots have already been taken");
+        self.taken = true;
+        let left_len = usize::min(self.left.1, self.len);
+        let (left, right) = unsafe {
+            (
+                slice::from_raw_parts_mut(self.left.0, left_len),
+                slice::from_raw_parts_mut(self.right, self.len - left_len),
+            )
+        };
+        let done = &self.done;
+        left.iter_mut()
+            .chain(right.iter_mut())
+            .enumerate()
+            .map(move |(index, place)| Slot { index, place, done })
+    }
+
+    /// Appends all reserved items to the ring buffer.
+    ///
+    /// Returns `Err` containing the writer itself if not all slots are written yet.
+    /// Dropping that writer leaks the items written so far (see [leaking](`Self#leaking`)).
+    pub fn publish(mut self) -> Result<(), Self> {
+        if !self.is_complete() {
+            return Err(self);
+        }
+        unsafe { self.prod.advance_write_index(self.len) };
+        self.len = 0;
+        *self.done.get_mut() = 0;
+        Ok(())
+    }
+}
+
+impl<'a, P: Producer + ?Sized> Drop for SlotWriter<'a, P> {
+    fn drop(&mut self) {
+        // It is unknown which slots are written unless all of them are, so partially written items are leaked.
+        if *self.done.get_mut() == self.len {
+            let left_len = usize::min(self.left.1, self.len);
+            unsafe {
+                ptr::drop_in_place(slice_assume_init_mut(slice::from_raw_parts_mut(self.left.0, left_len)));
+                ptr::drop_in_place(slice_assume_init_mut(slice::from_raw_parts_mut(self.right, self.len - left_len)));
+            }
+        }
+    }
+}
+
+/// Single reserved slot of [`SlotWriter`].
+///
+/// If the slot is dropped without being written then the writer never becomes complete.
+pub struct Slot<'a, T> {
+    index: usize,
+    place: &'a mut MaybeUninit<T>,
+    done: &'a AtomicUsize,
+}
+
+impl<'a, T> Slot<'a, T> {
+    /// Position of the slot in the reservation.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Writes an item to the slot and marks it complete.
+    pub fn write(self, elem: T) {
+        self.place.write(elem);
+        self.done.fetch_add(1, Ordering::Release);
+    }
 }
 
 /// Trait used for delegating consumer methods.
@@ -187,6 +637,16 @@ where
         self.base_mut().try_push(elem)
     }
 
+    #[inline]
+    fn try_push_with<F: FnOnce() -> Self::Item>(&mut self, f: F) -> Result<(), F> {
+        self.base_mut().try_push_with(f)
+    }
+
+    #[inline]
+    unsafe fn try_push_init<F: FnOnce(&mut MaybeUninit<Self::Item>)>(&mut self, f: F) -> Result<(), F> {
+        self.base_mut().try_push_init(f)
+    }
+
     #[inline]
     fn push_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: I) -> usize {
         self.base_mut().push_iter(iter)
@@ -199,10 +659,50 @@ where
     {
         self.base_mut().push_slice(elems)
     }
+
+    #[inline]
+    fn try_push_array<const N: usize>(&mut self, elems: [Self::Item; N]) -> Result<(), [Self::Item; N]> {
+        self.base_mut().try_push_array(elems)
+    }
+
+    #[inline]
+    fn push_slice_cloned(&mut self, elems: &[Self::Item]) -> usize
+    where
+        Self::Item: Clone,
+    {
+        self.base_mut().push_slice_cloned(elems)
+    }
+
+    #[inline]
+    fn try_push_slice_exact(&mut self, elems: &[Self::Item]) -> Result<(), usize>
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().try_push_slice_exact(elems)
+    }
+
+    #[inline]
+    unsafe fn push_slice_unchecked(&mut self, elems: &[Self::Item])
+    where
+        Self::Item: Copy,
+    {
+        self.base_mut().push_slice_unchecked(elems)
+    }
 }
 
 macro_rules! impl_producer_traits {
     ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
+        /// Items that don't fit into the ring buffer are left in the iterator.
+        ///
+        /// Use [`Producer::try_extend`]($crate::traits::Producer::try_extend) to get them back.
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? core::iter::Extend<<$type $(< $( $param ),+ >)? as $crate::traits::Observer>::Item> for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Producer,
+        {
+            fn extend<It: IntoIterator<Item = <Self as $crate::traits::Observer>::Item>>(&mut self, iter: It) {
+                self.push_iter(iter.into_iter());
+            }
+        }
 
         #[cfg(feature = "std")]
         impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? std::io::Write for $type $(< $( $param ),+ >)?
@@ -235,6 +735,53 @@ macro_rules! impl_producer_traits {
                 }
             }
          }
+
+        #[cfg(feature = "embedded-io")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? embedded_io::WriteReady for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Producer<Item = u8>,
+        {
+            fn write_ready(&mut self) -> Result<bool, Self::Error> {
+                Ok(!self.is_full())
+            }
+        }
     };
  }
 pub(crate) use impl_producer_traits;
+
+/// Implements blocking I/O traits which wait by spinning until the opposite end makes progress.
+///
+/// Not applicable to frozen wrappers because they don't see the opposite end progress without synchronization.
+macro_rules! impl_blocking_producer_traits {
+    ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
+        /// Blocks by spinning while the ring buffer is full and consumer exists.
+        ///
+        /// Returns [`BrokenPipe`](`embedded_io::ErrorKind::BrokenPipe`) if the ring buffer is full and there is no consumer.
+        #[cfg(feature = "embedded-io")]
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? embedded_io::Write for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Producer<Item = u8>,
+        {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+                if buf.is_empty() {
+                    return Ok(0);
+                }
+                loop {
+                    let closed = !self.read_is_held();
+                    let n = self.push_slice(buf);
+                    if n != 0 {
+                        return Ok(n);
+                    }
+                    if closed {
+                        return Err(embedded_io::ErrorKind::BrokenPipe);
+                    }
+                    core::hint::spin_loop();
+                }
+            }
+            fn flush(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use impl_blocking_producer_traits;
@@ -1,8 +1,17 @@
 use super::{
     consumer::{Consumer, DelegateConsumer},
+    observer::Occupancy,
     producer::{DelegateProducer, Producer},
+    utils::index_add,
     Observer,
 };
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Bound, RangeBounds},
+    ptr,
+};
 
 /// An abstract ring buffer that exclusively owns its data.
 pub trait RingBuffer: Observer + Consumer + Producer {
@@ -23,6 +32,92 @@ pub trait RingBuffer: Observer + Consumer + Producer {
     /// Must not be set to `false` while producer exists.
     unsafe fn hold_write(&self, flag: bool) -> bool;
 
+    /// Moves both read and write indices by `count` places forward keeping stored items count the same.
+    ///
+    /// Implementations should not count the moved places in [`Observer::total_pushed`] and [`Observer::total_popped`].
+    ///
+    /// # Safety
+    ///
+    /// Items of the storage must be moved accordingly before that.
+    unsafe fn shift_indices(&self, count: usize) {
+        self.set_write_index(index_add(self, self.write_index(), count));
+        self.set_read_index(index_add(self, self.read_index(), count));
+    }
+
+    /// Borrows a contiguous vacant slice of at least `min_len` items.
+    ///
+    /// If vacant space is split by the end of the storage so that its first part is shorter than `min_len`,
+    /// then stored items are moved to the beginning of the storage making vacant space contiguous.
+    /// This takes `O(capacity)` time, so it is intended for rare cases like writing headers that must not be split.
+    ///
+    /// Returns `None` if there are less than `min_len` vacant items.
+    fn vacant_contiguous_mut(&mut self, min_len: usize) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        if self.vacant_len() < min_len {
+            return None;
+        }
+        if self.vacant_slices_mut().0.len() < min_len {
+            move_to_start(self);
+        }
+        Some(self.vacant_slices_mut().0)
+    }
+
+    /// Moves stored items so that they are located in a single contiguous slice and returns it.
+    ///
+    /// Like [`VecDeque::make_contiguous`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.make_contiguous)
+    /// items are moved only if they are split by the end of the storage. This takes `O(capacity)` time.
+    fn make_contiguous(&mut self) -> &mut [Self::Item] {
+        if !self.as_slices().1.is_empty() {
+            move_to_start(self);
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Returns an iterator that removes the `range` of occupied items, where `0` is the eldest one.
+    ///
+    /// Like [`VecDeque::drain`](https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.drain)
+    /// the ring buffer is modified before any item is yielded: items preceding the range are moved towards the most recent one
+    /// and read index is advanced, so the range is placed outside of the occupied region.
+    /// Items of the range which haven't been taken from the iterator are dropped when it is dropped.
+    /// If the iterator is leaked (e.g. by [`mem::forget`](core::mem::forget)) then only these items are leaked.
+    ///
+    /// This takes `O(range.end)` time.
+    ///
+    /// *Panics if the range is decreasing or its end is greater than the number of occupied items.*
+    fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, Self> {
+        let (left, right) = unsafe { self.occupied_slices_mut() };
+        let (left, right) = (
+            (left.as_mut_ptr() as *mut Self::Item, left.len()),
+            right.as_mut_ptr() as *mut Self::Item,
+        );
+        let len = self.occupied_len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("Range start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("Range end overflow"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "Range start is greater than its end");
+        assert!(end <= len, "Range end is out of bounds");
+        let drain = Drain {
+            left,
+            right,
+            front: 0,
+            back: end - start,
+            _ghost: PhantomData,
+        };
+        // Range is moved to the front by rotation and then removed from the ring buffer.
+        // Ring buffer is borrowed exclusively, so vacant space containing the range isn't modified while the iterator exists.
+        drain.reverse(0, start);
+        drain.reverse(start, end);
+        drain.reverse(0, end);
+        unsafe { self.advance_read_index(end - start) };
+        drain
+    }
+
     /// Pushes an item to the ring buffer overwriting the latest item if the buffer is full.
     ///
     /// Returns overwritten item if overwriting took place.
@@ -49,17 +144,105 @@ pub trait RingBuffer: Observer + Consumer + Producer {
     where
         Self::Item: Copy,
     {
-        if elems.len() > self.vacant_len() {
-            self.skip(usize::min(elems.len() - self.vacant_len(), self.occupied_len()));
+        let Occupancy { occupied, vacant } = self.occupancy();
+        if elems.len() > vacant {
+            self.skip(usize::min(elems.len() - vacant, occupied));
         }
-        self.push_slice(if elems.len() > self.vacant_len() {
-            &elems[(elems.len() - self.vacant_len())..]
+        let capacity = self.capacity().get();
+        self.push_slice(if elems.len() > capacity {
+            unsafe { elems.get_unchecked((elems.len() - capacity)..) }
         } else {
             elems
         });
     }
 }
 
+/// Rotates the storage so that the eldest item is located at its beginning.
+fn move_to_start<R: RingBuffer + ?Sized>(this: &mut R) {
+    let capacity = this.capacity().get();
+    let offset = this.read_index() % capacity;
+    unsafe {
+        this.unsafe_slices_mut(0, capacity).0.rotate_left(offset);
+        this.shift_indices(capacity - offset);
+    }
+}
+
+/// An iterator that removes a range of items from the ring buffer.
+///
+/// Created by [`RingBuffer::drain`].
+pub struct Drain<'a, R: RingBuffer + ?Sized> {
+    left: (*mut R::Item, usize),
+    right: *mut R::Item,
+    front: usize,
+    back: usize,
+    _ghost: PhantomData<&'a mut R>,
+}
+
+impl<'a, R: RingBuffer + ?Sized> Drain<'a, R> {
+    fn elem(&self, index: usize) -> *mut R::Item {
+        let (left, left_len) = self.left;
+        if index < left_len {
+            unsafe { left.add(index) }
+        } else {
+            unsafe { self.right.add(index - left_len) }
+        }
+    }
+    fn reverse(&self, mut start: usize, mut end: usize) {
+        while start + 1 < end {
+            end -= 1;
+            unsafe { ptr::swap(self.elem(start), self.elem(end)) };
+            start += 1;
+        }
+    }
+}
+
+impl<'a, R: RingBuffer + ?Sized> Iterator for Drain<'a, R> {
+    type Item = R::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let elem = unsafe { self.elem(self.front).read() };
+            self.front += 1;
+            Some(elem)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, R: RingBuffer + ?Sized> DoubleEndedIterator for Drain<'a, R> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(unsafe { self.elem(self.back).read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, R: RingBuffer + ?Sized> ExactSizeIterator for Drain<'a, R> {}
+
+impl<'a, R: RingBuffer + ?Sized> FusedIterator for Drain<'a, R> {}
+
+impl<'a, R: RingBuffer + ?Sized> Drop for Drain<'a, R> {
+    fn drop(&mut self) {
+        // Items are already removed from the ring buffer, so if dropping of some item panics the rest is leaked.
+        while self.front < self.back {
+            let elem = self.elem(self.front);
+            self.front += 1;
+            unsafe { ptr::drop_in_place(elem) };
+        }
+    }
+}
+
 /// Trait used for delegating owning ring buffer methods.
 pub trait DelegateRingBuffer: DelegateProducer + DelegateConsumer
 where
@@ -77,6 +260,19 @@ where
     unsafe fn hold_write(&self, flag: bool) -> bool {
         self.base().hold_write(flag)
     }
+    unsafe fn shift_indices(&self, count: usize) {
+        self.base().shift_indices(count)
+    }
+
+    #[inline]
+    fn vacant_contiguous_mut(&mut self, min_len: usize) -> Option<&mut [MaybeUninit<Self::Item>]> {
+        self.base_mut().vacant_contiguous_mut(min_len)
+    }
+
+    #[inline]
+    fn make_contiguous(&mut self) -> &mut [Self::Item] {
+        self.base_mut().make_contiguous()
+    }
 
     #[inline]
     fn push_overwrite(&mut self, elem: Self::Item) -> Option<Self::Item> {
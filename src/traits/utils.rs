@@ -20,3 +20,39 @@ pub trait Based {
 pub fn modulus<O: Observer + ?Sized>(this: &O) -> NonZeroUsize {
     unsafe { NonZeroUsize::new_unchecked(2 * this.capacity().get()) }
 }
+
+/// Returns `(index + count) % modulus` without overflow.
+///
+/// Uses bitmask instead of division when capacity is a power of two.
+///
+/// `index` must be less than [`modulus`].
+#[inline]
+pub fn index_add<O: Observer + ?Sized>(this: &O, index: usize, count: usize) -> usize {
+    let modulus = modulus(this).get();
+    if modulus.is_power_of_two() {
+        return index.wrapping_add(count) & (modulus - 1);
+    }
+    // Count rarely exceeds modulus, so division is avoided in common case.
+    let count = if count < modulus { count } else { count % modulus };
+    if index < modulus - count {
+        index + count
+    } else {
+        index - (modulus - count)
+    }
+}
+
+/// Returns `(end - start) % modulus` without overflow.
+///
+/// `start` and `end` must be less than [`modulus`].
+#[inline]
+pub fn index_sub<O: Observer + ?Sized>(this: &O, end: usize, start: usize) -> usize {
+    let modulus = modulus(this).get();
+    if modulus.is_power_of_two() {
+        return end.wrapping_sub(start) & (modulus - 1);
+    }
+    if end >= start {
+        end - start
+    } else {
+        modulus - (start - end)
+    }
+}
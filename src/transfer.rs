@@ -1,4 +1,4 @@
-use crate::{consumer::Consumer, producer::Producer};
+use crate::{consumer::Consumer, producer::Producer, utils::prefetch};
 
 /// Moves at most `count` items from the `src` consumer to the `dst` producer.
 ///
@@ -9,6 +9,10 @@ use crate::{consumer::Consumer, producer::Producer};
 pub fn transfer<T, C: Consumer<Item = T>, P: Producer<Item = T>>(src: &mut C, dst: &mut P, count: Option<usize>) -> usize {
     let (src_left, src_right) = src.occupied_slices();
     let (dst_left, dst_right) = dst.vacant_slices_mut();
+    if count.is_none_or(|count| count > src_left.len() || count > dst_left.len()) {
+        prefetch(src_left, src_right);
+        prefetch(dst_left, dst_right);
+    }
     let src_iter = src_left.iter().chain(src_right.iter());
     let dst_iter = dst_left.iter_mut().chain(dst_right.iter_mut());
 
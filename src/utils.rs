@@ -1,12 +1,43 @@
 #[cfg(feature = "alloc")]
 use alloc::{boxed::Box, vec::Vec};
 use core::{
+    cmp,
     mem::{self, MaybeUninit},
     ptr,
 };
 
+/// Value padded to the length of a cache line to prevent false sharing between producer and consumer.
+#[cfg(feature = "cache_padding")]
+pub type Padded<T> = crossbeam_utils::CachePadded<T>;
+
+/// Value without padding.
+#[cfg(not(feature = "cache_padding"))]
+#[repr(transparent)]
+pub struct Padded<T> {
+    value: T,
+}
+#[cfg(not(feature = "cache_padding"))]
+impl<T> Padded<T> {
+    pub const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+#[cfg(not(feature = "cache_padding"))]
+impl<T> core::ops::Deref for Padded<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Evaluation of [`Self::ASSERT`] fails to compile if `N` is zero.
+pub struct NonZeroCapacity<const N: usize>;
+impl<const N: usize> NonZeroCapacity<N> {
+    pub const ASSERT: () = assert!(N != 0, "Ring buffer capacity must be greater than zero");
+}
+
 // TODO: Remove on `maybe_uninit_uninit_array` stabilization.
-pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
     unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
 }
 
@@ -25,17 +56,53 @@ pub unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T]
 }
 
 // TODO: Remove on `maybe_uninit_write_slice` stabilization.
+/// Copies items from `src` to `dst`.
+///
+/// Only `min(dst.len(), src.len())` items are copied, so this function never panics.
 pub fn write_slice<'a, T: Copy>(dst: &'a mut [MaybeUninit<T>], src: &[T]) -> &'a mut [T] {
-    let uninit_src: &[MaybeUninit<T>] = unsafe { mem::transmute(src) };
-    dst.copy_from_slice(uninit_src);
-    unsafe { slice_assume_init_mut(dst) }
+    debug_assert_eq!(dst.len(), src.len());
+    let len = cmp::min(dst.len(), src.len());
+    unsafe {
+        ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr() as *mut T, len);
+        slice_assume_init_mut(dst.get_unchecked_mut(..len))
+    }
 }
 
+/// Moves items from `src` to `dst`.
+///
+/// Only `min(dst.len(), src.len())` items are moved, so this function never panics.
 pub fn move_uninit_slice<T>(dst: &mut [MaybeUninit<T>], src: &[MaybeUninit<T>]) {
-    assert_eq!(dst.len(), src.len());
-    for i in 0..dst.len() {
-        unsafe { *dst.get_unchecked_mut(i) = ptr::read(src.get_unchecked(i) as *const _) };
+    debug_assert_eq!(dst.len(), src.len());
+    let len = cmp::min(dst.len(), src.len());
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len) };
+}
+
+/// Hints the CPU to load the beginning of `next` slice into cache while `current` slice is being copied.
+///
+/// The second of ring buffer slices is located at the opposite end of the storage, so for large rings it is usually cold.
+/// Prefetch is issued only if `current` slice is large enough for the load to complete before `next` slice is reached.
+///
+/// Does nothing on architectures without stable prefetch intrinsics.
+#[inline]
+pub fn prefetch<T>(current: &[T], next: &[T]) {
+    #[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse")))]
+    {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        /// Count of bytes prefetched at the beginning of a slice.
+        const PREFETCH_LEN: usize = 256;
+
+        if mem::size_of_val(current) >= PREFETCH_LEN {
+            let ptr = next.as_ptr() as *const i8;
+            for offset in (0..cmp::min(mem::size_of_val(next), PREFETCH_LEN)).step_by(64) {
+                unsafe { _mm_prefetch::<_MM_HINT_T0>(ptr.add(offset)) };
+            }
+        }
     }
+    #[cfg(not(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse"))))]
+    let _ = (current, next);
 }
 
 pub fn array_to_uninit<T, const N: usize>(value: [T; N]) -> [MaybeUninit<T>; N] {
@@ -43,6 +110,20 @@ pub fn array_to_uninit<T, const N: usize>(value: [T; N]) -> [MaybeUninit<T>; N]
     let ptr = &value as *const _ as *const [MaybeUninit<T>; N];
     unsafe { ptr.read() }
 }
+// TODO: Remove on `maybe_uninit_array_assume_init` stabilization.
+pub unsafe fn array_assume_init<T, const N: usize>(value: [MaybeUninit<T>; N]) -> [T; N] {
+    let value = mem::ManuallyDrop::new(value);
+    let ptr = &value as *const _ as *const [T; N];
+    ptr.read()
+}
+
+#[cfg(feature = "heapless")]
+pub fn heapless_vec_to_uninit_array<T, const N: usize>(value: heapless::Vec<T, N>) -> [MaybeUninit<T>; N] {
+    let value = mem::ManuallyDrop::new(value);
+    let mut array = uninit_array();
+    unsafe { ptr::copy_nonoverlapping(value.as_ptr(), array.as_mut_ptr() as *mut T, value.len()) };
+    array
+}
 
 #[cfg(feature = "alloc")]
 pub fn vec_to_uninit<T>(value: Vec<T>) -> Vec<MaybeUninit<T>> {
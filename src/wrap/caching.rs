@@ -4,16 +4,24 @@
 
 use super::{direct::Obs, frozen::Frozen, traits::Wrap};
 use crate::{
-    rb::RbRef,
+    rb::{AtomicIndex, RbRef, SharedRb},
+    storage::Storage,
     traits::{
-        consumer::{impl_consumer_traits, Consumer},
-        producer::{impl_producer_traits, Producer},
+        consumer::{impl_blocking_consumer_traits, impl_consumer_traits, Consumer, DrainIter},
+        observer::impl_observer_traits,
+        producer::{impl_blocking_producer_traits, impl_producer_traits, Producer},
         Observer,
     },
 };
 use core::{mem::MaybeUninit, num::NonZeroUsize};
 
 /// Caching wrapper of a ring buffer.
+///
+/// Has the same layout as [`Frozen`].
+///
+/// Cached indices are updated on every operation, so if producer and consumer are stored next to each other
+/// (e.g. in the same struct) they should be placed on different cache lines to avoid false sharing.
+#[repr(transparent)]
 pub struct Caching<R: RbRef, const P: bool, const C: bool> {
     frozen: Frozen<R, P, C>,
 }
@@ -30,6 +38,12 @@ impl<R: RbRef, const P: bool, const C: bool> Caching<R, P, C> {
     pub fn new(rb: R) -> Self {
         Self { frozen: Frozen::new(rb) }
     }
+    /// Create a new ring buffer cached wrapper.
+    ///
+    /// Returns `Err` containing `rb` if wrapper with matching rights already exists.
+    pub fn try_new(rb: R) -> Result<Self, R> {
+        Frozen::try_new(rb).map(|frozen| Self { frozen })
+    }
 
     /// Get ring buffer observer.
     pub fn observe(&self) -> Obs<R> {
@@ -42,6 +56,16 @@ impl<R: RbRef, const P: bool, const C: bool> Caching<R, P, C> {
     }
 }
 
+impl<S: Storage + ?Sized, I: AtomicIndex, R: RbRef<Rb = SharedRb<S, I>>> CachingCons<R> {
+    /// Returns an iterator that removes items until the ring buffer is empty and producer is dropped.
+    ///
+    /// Unlike [`IntoIterator`] implementation it doesn't stop when the ring buffer becomes empty
+    /// but spins waiting for items while producer exists, so it should be used only when producer runs concurrently.
+    pub fn into_drain_iter(self) -> DrainIter<Self> {
+        DrainIter::new(self)
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Wrap for Caching<R, P, C> {
     type RbRef = R;
 
@@ -51,6 +75,14 @@ impl<R: RbRef, const P: bool, const C: bool> Wrap for Caching<R, P, C> {
     fn into_rb_ref(self) -> R {
         self.frozen.into_rb_ref()
     }
+    unsafe fn into_rb_ref_held(self) -> R {
+        self.frozen.into_rb_ref_held()
+    }
+    unsafe fn from_rb_ref_held(rb: R) -> Self {
+        Self {
+            frozen: Frozen::from_rb_ref_held(rb),
+        }
+    }
 }
 
 impl<R: RbRef, const P: bool, const C: bool> AsRef<Self> for Caching<R, P, C> {
@@ -87,6 +119,20 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Caching<R, P, C> {
         self.frozen.write_index()
     }
 
+    #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        self.frozen.rb().indices_relaxed()
+    }
+
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        self.frozen.total_pushed()
+    }
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        self.frozen.total_popped()
+    }
+
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.frozen.unsafe_slices(start, end)
     }
@@ -121,6 +167,42 @@ impl<R: RbRef> Producer for CachingProd<R> {
         }
         r
     }
+
+    fn try_push_with<F: FnOnce() -> Self::Item>(&mut self, f: F) -> Result<(), F> {
+        if self.frozen.is_full() {
+            self.frozen.fetch();
+        }
+        let r = self.frozen.try_push_with(f);
+        if r.is_ok() {
+            self.frozen.commit();
+        }
+        r
+    }
+
+    unsafe fn try_push_init<F: FnOnce(&mut MaybeUninit<Self::Item>)>(&mut self, f: F) -> Result<(), F> {
+        if self.frozen.is_full() {
+            self.frozen.fetch();
+        }
+        let r = self.frozen.try_push_init(f);
+        if r.is_ok() {
+            self.frozen.commit();
+        }
+        r
+    }
+
+    fn push_slice(&mut self, elems: &[Self::Item]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        if self.frozen.vacant_len() < elems.len() {
+            self.frozen.fetch();
+        }
+        let n = self.frozen.push_slice(elems);
+        if n > 0 {
+            self.frozen.commit();
+        }
+        n
+    }
 }
 
 impl<R: RbRef> Consumer for CachingCons<R> {
@@ -140,7 +222,22 @@ impl<R: RbRef> Consumer for CachingCons<R> {
         }
         r
     }
+
+    fn pop_slice_uninit(&mut self, elems: &mut [MaybeUninit<Self::Item>]) -> usize {
+        if self.frozen.occupied_len() < elems.len() {
+            self.frozen.fetch();
+        }
+        let n = self.frozen.pop_slice_uninit(elems);
+        if n > 0 {
+            self.frozen.commit();
+        }
+        n
+    }
 }
 
+impl_observer_traits!(CachingProd<R: RbRef>);
+impl_observer_traits!(CachingCons<R: RbRef>);
 impl_producer_traits!(CachingProd<R: RbRef>);
 impl_consumer_traits!(CachingCons<R: RbRef>);
+impl_blocking_producer_traits!(CachingProd<R: RbRef>);
+impl_blocking_consumer_traits!(CachingCons<R: RbRef>);
@@ -4,13 +4,17 @@
 
 use super::{frozen::Frozen, traits::Wrap};
 use crate::{
-    rb::RbRef,
+    rb::{AtomicIndex, RbRef, SharedRb},
+    storage::Storage,
     traits::{
-        consumer::{impl_consumer_traits, Consumer},
-        producer::{impl_producer_traits, Producer},
+        consumer::{impl_blocking_consumer_traits, impl_consumer_traits, Consumer, DrainIter},
+        observer::impl_observer_traits,
+        producer::{impl_blocking_producer_traits, impl_producer_traits, Producer},
         Observer, RingBuffer,
     },
 };
+#[cfg(feature = "alloc")]
+use alloc::sync::{Arc, Weak};
 use core::{
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
@@ -35,18 +39,63 @@ impl<R: RbRef> Clone for Obs<R> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<B: RingBuffer + ?Sized> Obs<Arc<B>> {
+    /// Get observer that doesn't keep the ring buffer alive.
+    pub fn downgrade(&self) -> WeakObs<B> {
+        WeakObs {
+            rb: Arc::downgrade(&self.rb),
+        }
+    }
+}
+
+/// Observer of a ring buffer that holds a weak reference to it.
+///
+/// The ring buffer is dropped when all its strong handles are dropped regardless of weak observers.
+#[cfg(feature = "alloc")]
+pub struct WeakObs<B: RingBuffer + ?Sized> {
+    rb: Weak<B>,
+}
+
+#[cfg(feature = "alloc")]
+impl<B: RingBuffer + ?Sized> WeakObs<B> {
+    /// Get observer of the ring buffer if it is still alive.
+    pub fn upgrade(&self) -> Option<Obs<Arc<B>>> {
+        self.rb.upgrade().map(|rb| Obs { rb })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<B: RingBuffer + ?Sized> Clone for WeakObs<B> {
+    fn clone(&self) -> Self {
+        Self { rb: self.rb.clone() }
+    }
+}
+
 impl<R: RbRef, const P: bool, const C: bool> Direct<R, P, C> {
     /// Create a new ring buffer direct wrapper.
     ///
     /// Panics if wrapper with matching rights already exists.
     pub fn new(rb: R) -> Self {
-        if P {
-            assert!(!unsafe { rb.rb().hold_write(true) });
+        match Self::try_new(rb) {
+            Ok(this) => this,
+            Err(_) => panic!("Ring buffer is already held"),
         }
-        if C {
-            assert!(!unsafe { rb.rb().hold_read(true) });
+    }
+    /// Create a new ring buffer direct wrapper.
+    ///
+    /// Returns `Err` containing `rb` if wrapper with matching rights already exists.
+    pub fn try_new(rb: R) -> Result<Self, R> {
+        if P && unsafe { rb.rb().hold_write(true) } {
+            return Err(rb);
         }
-        Self { rb }
+        if C && unsafe { rb.rb().hold_read(true) } {
+            if P {
+                unsafe { rb.rb().hold_write(false) };
+            }
+            return Err(rb);
+        }
+        Ok(Self { rb })
     }
 
     /// Get ring buffer observer.
@@ -85,6 +134,13 @@ impl<R: RbRef, const P: bool, const C: bool> Wrap for Direct<R, P, C> {
             ptr::read(&this.rb)
         }
     }
+    unsafe fn into_rb_ref_held(self) -> R {
+        let this = ManuallyDrop::new(self);
+        ptr::read(&this.rb)
+    }
+    unsafe fn from_rb_ref_held(rb: R) -> Self {
+        Self { rb }
+    }
 }
 
 impl<R: RbRef, const P: bool, const C: bool> AsRef<Self> for Direct<R, P, C> {
@@ -114,6 +170,18 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Direct<R, P, C> {
         self.rb().write_index()
     }
     #[inline]
+    fn indices_relaxed(&self) -> (usize, usize) {
+        self.rb().indices_relaxed()
+    }
+    #[inline]
+    fn total_pushed(&self) -> Option<u64> {
+        self.rb().total_pushed()
+    }
+    #[inline]
+    fn total_popped(&self) -> Option<u64> {
+        self.rb().total_popped()
+    }
+    #[inline]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices(start, end)
     }
@@ -131,6 +199,16 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Direct<R, P, C> {
     }
 }
 
+impl<S: Storage + ?Sized, I: AtomicIndex, R: RbRef<Rb = SharedRb<S, I>>> Cons<R> {
+    /// Returns an iterator that removes items until the ring buffer is empty and producer is dropped.
+    ///
+    /// Unlike [`IntoIterator`] implementation it doesn't stop when the ring buffer becomes empty
+    /// but spins waiting for items while producer exists, so it should be used only when producer runs concurrently.
+    pub fn into_drain_iter(self) -> DrainIter<Self> {
+        DrainIter::new(self)
+    }
+}
+
 impl<R: RbRef> Producer for Prod<R> {
     #[inline]
     unsafe fn set_write_index(&self, value: usize) {
@@ -151,5 +229,10 @@ impl<R: RbRef, const P: bool, const C: bool> Drop for Direct<R, P, C> {
     }
 }
 
+impl_observer_traits!(Obs<R: RbRef>);
+impl_observer_traits!(Prod<R: RbRef>);
+impl_observer_traits!(Cons<R: RbRef>);
 impl_producer_traits!(Prod<R: RbRef>);
 impl_consumer_traits!(Cons<R: RbRef>);
+impl_blocking_producer_traits!(Prod<R: RbRef>);
+impl_blocking_consumer_traits!(Cons<R: RbRef>);
@@ -7,7 +7,9 @@ use crate::{
     rb::RbRef,
     traits::{
         consumer::{impl_consumer_traits, Consumer},
+        observer::impl_observer_traits,
         producer::{impl_producer_traits, Producer},
+        utils::index_sub,
         Observer, RingBuffer,
     },
 };
@@ -19,6 +21,18 @@ use core::{
 };
 
 /// Frozen wrapper of the ring buffer.
+///
+/// # Layout
+///
+/// The wrapper has a stable layout so it can be embedded into foreign structures.
+/// It is `#[repr(C)]` and consists of the following fields in order:
+///
+/// + ring buffer reference `R` (when `R` is `&B` it is a single non-null pointer to the ring buffer),
+/// + cached read index (`usize`),
+/// + cached write index (`usize`).
+///
+/// Cached indices are modulo `2 * capacity` like ring buffer indices.
+#[repr(C)]
 pub struct Frozen<R: RbRef, const P: bool, const C: bool> {
     rb: R,
     read: Cell<usize>,
@@ -42,13 +56,25 @@ impl<R: RbRef, const P: bool, const C: bool> Frozen<R, P, C> {
     ///
     /// Panics if wrapper with matching rights already exists.
     pub fn new(rb: R) -> Self {
-        if P {
-            assert!(!unsafe { rb.rb().hold_write(true) });
+        match Self::try_new(rb) {
+            Ok(this) => this,
+            Err(_) => panic!("Ring buffer is already held"),
         }
-        if C {
-            assert!(!unsafe { rb.rb().hold_read(true) });
+    }
+    /// Create a new ring buffer frozen wrapper.
+    ///
+    /// Returns `Err` containing `rb` if wrapper with matching rights already exists.
+    pub fn try_new(rb: R) -> Result<Self, R> {
+        if P && unsafe { rb.rb().hold_write(true) } {
+            return Err(rb);
         }
-        unsafe { Self::new_unchecked(rb) }
+        if C && unsafe { rb.rb().hold_read(true) } {
+            if P {
+                unsafe { rb.rb().hold_write(false) };
+            }
+            return Err(rb);
+        }
+        Ok(unsafe { Self::new_unchecked(rb) })
     }
 
     /// Create wrapper without checking that such wrapper already exists.
@@ -93,6 +119,14 @@ impl<R: RbRef, const P: bool, const C: bool> Wrap for Frozen<R, P, C> {
             ptr::read(&this.rb)
         }
     }
+    unsafe fn into_rb_ref_held(self) -> R {
+        self.commit();
+        let this = ManuallyDrop::new(self);
+        ptr::read(&this.rb)
+    }
+    unsafe fn from_rb_ref_held(rb: R) -> Self {
+        Self::new_unchecked(rb)
+    }
 }
 
 impl<R: RbRef, const P: bool, const C: bool> AsRef<Self> for Frozen<R, P, C> {
@@ -165,6 +199,25 @@ impl<R: RbRef, const P: bool, const C: bool> Observer for Frozen<R, P, C> {
         self.write.get()
     }
 
+    /// Includes items pushed but not committed yet.
+    fn total_pushed(&self) -> Option<u64> {
+        let total = self.rb().total_pushed()?;
+        Some(if P {
+            total + index_sub(self, self.write.get(), self.rb().write_index()) as u64
+        } else {
+            total
+        })
+    }
+    /// Includes items popped but not committed yet.
+    fn total_popped(&self) -> Option<u64> {
+        let total = self.rb().total_popped()?;
+        Some(if C {
+            total + index_sub(self, self.read.get(), self.rb().read_index()) as u64
+        } else {
+            total
+        })
+    }
+
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices(start, end)
     }
@@ -203,5 +256,70 @@ impl<R: RbRef, const P: bool, const C: bool> Drop for Frozen<R, P, C> {
     }
 }
 
+impl_observer_traits!(FrozenProd<R: RbRef>);
+impl_observer_traits!(FrozenCons<R: RbRef>);
 impl_producer_traits!(FrozenProd<R: RbRef>);
 impl_consumer_traits!(FrozenCons<R: RbRef>);
+
+/// Blocks by spinning while the ring buffer is full and consumer exists.
+///
+/// If the ring buffer is full, the producer is synchronized ([`Self::sync`]) so that written items become visible to the consumer
+/// and space freed by the consumer becomes visible to the producer.
+///
+/// Returns [`BrokenPipe`](`embedded_io::ErrorKind::BrokenPipe`) if the ring buffer is full and there is no consumer.
+#[cfg(feature = "embedded-io")]
+impl<R: RbRef> embedded_io::Write for FrozenProd<R>
+where
+    Self: Producer<Item = u8>,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let mut n = self.push_slice(buf);
+        while n == 0 {
+            let closed = !self.read_is_held();
+            self.sync();
+            n = self.push_slice(buf);
+            if n == 0 && closed {
+                return Err(embedded_io::ErrorKind::BrokenPipe);
+            }
+            core::hint::spin_loop();
+        }
+        Ok(n)
+    }
+    /// Commits written items.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.commit();
+        Ok(())
+    }
+}
+
+/// Blocks by spinning while the ring buffer is empty and producer exists.
+///
+/// If the ring buffer is empty, the consumer is synchronized ([`Self::sync`]) so that items written by the producer become visible
+/// and space of read items becomes visible to the producer.
+///
+/// Returns `Ok(0)` (end of file) if the ring buffer is empty and there is no producer.
+#[cfg(feature = "embedded-io")]
+impl<R: RbRef> embedded_io::Read for FrozenCons<R>
+where
+    Self: Consumer<Item = u8>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let mut n = self.pop_slice(buf);
+        while n == 0 {
+            let closed = !self.write_is_held();
+            self.sync();
+            n = self.pop_slice(buf);
+            if n == 0 && closed {
+                break;
+            }
+            core::hint::spin_loop();
+        }
+        Ok(n)
+    }
+}
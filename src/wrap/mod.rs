@@ -4,6 +4,8 @@ pub mod frozen;
 mod traits;
 
 pub use caching::{CachingCons, CachingProd};
+#[cfg(feature = "alloc")]
+pub use direct::WeakObs;
 pub use direct::{Cons, Obs, Prod};
 pub use frozen::{FrozenCons, FrozenProd};
 pub use traits::*;
@@ -1,4 +1,4 @@
-use crate::rb::RbRef;
+use crate::{rb::RbRef, traits::RingBuffer};
 
 /// Ring buffer wrapper that contains reference to the ring buffer inside.
 pub trait Wrap: AsRef<Self> + AsMut<Self> {
@@ -13,4 +13,45 @@ pub trait Wrap: AsRef<Self> + AsMut<Self> {
     fn rb_ref(&self) -> &Self::RbRef;
     /// Destructure into underlying ring buffer reference.
     fn into_rb_ref(self) -> Self::RbRef;
+
+    /// Destructure into underlying ring buffer reference keeping the ring buffer held by this wrapper.
+    ///
+    /// Pending changes are synchronized with the ring buffer.
+    ///
+    /// # Safety
+    ///
+    /// The reference must be passed back to [`Self::from_rb_ref_held`], or the hold must be released otherwise.
+    unsafe fn into_rb_ref_held(self) -> Self::RbRef;
+    /// Create wrapper from ring buffer reference that is already held for it.
+    ///
+    /// # Safety
+    ///
+    /// The reference must be obtained from [`Self::into_rb_ref_held`] of the same wrapper type.
+    unsafe fn from_rb_ref_held(rb: Self::RbRef) -> Self;
+
+    /// Whether all other handles of the ring buffer (its counterpart and observers including weak ones) have been dropped.
+    ///
+    /// Always `false` if the ring buffer is borrowed.
+    fn is_abandoned(&self) -> bool {
+        self.rb_ref().is_unique()
+    }
+    /// Take the underlying ring buffer with remaining items if it [is abandoned](`Self::is_abandoned`).
+    ///
+    /// Otherwise returns `Err` containing `self`.
+    fn try_into_rb(self) -> Result<<Self::RbRef as RbRef>::Rb, Self>
+    where
+        Self: Sized,
+        <Self::RbRef as RbRef>::Rb: Sized,
+    {
+        match unsafe { self.into_rb_ref_held() }.try_into_rb() {
+            Ok(rb) => {
+                unsafe {
+                    rb.hold_read(false);
+                    rb.hold_write(false);
+                }
+                Ok(rb)
+            }
+            Err(rb) => Err(unsafe { Self::from_rb_ref_held(rb) }),
+        }
+    }
 }